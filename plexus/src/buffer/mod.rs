@@ -87,12 +87,14 @@
 mod builder;
 
 use itertools::Itertools;
-use num::{Integer, NumCast, Unsigned};
+use num::{Integer, NumCast, ToPrimitive, Unsigned};
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::iter::FromIterator;
 use std::vec;
 use theon::adjunct::Map;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::{AsPosition, Position};
 use thiserror::Error;
 use typenum::{self, NonZero, Unsigned as _, U3, U4};
 
@@ -100,6 +102,7 @@ use crate::buffer::builder::BufferBuilder;
 use crate::builder::{Buildable, MeshBuilder};
 use crate::constant::{Constant, ToType, TypeOf};
 use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
+use crate::geometry::weld;
 use crate::geometry::{FromGeometry, IntoGeometry};
 use crate::index::{
     BufferOf, Flat, Flat3, Flat4, FromIndexer, Grouping, HashIndexer, IndexBuffer, IndexOf,
@@ -508,6 +511,62 @@ where
             .extend(buffer.indices.drain(..).map(|index| index.into() + offset));
         Ok(())
     }
+
+    /// Welds vertices in a flat `MeshBuffer` with positions that are within
+    /// the given distance of each other and combines their data using the
+    /// given function.
+    ///
+    /// The index buffer is reindexed into the welded vertices and any vertices
+    /// that are not indexed are dropped. See [`WeldVertices`].
+    ///
+    /// # Examples
+    ///
+    /// Welding a triangle soup:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::index::Flat3;
+    /// use plexus::prelude::*;
+    ///
+    /// let buffer = MeshBuffer::<Flat3, Point2<f64>>::from_raw_buffers(
+    ///     vec![0usize, 1, 2, 3, 4, 5],
+    ///     vec![
+    ///         (0.0, 0.0),
+    ///         (1.0, 0.0),
+    ///         (0.0, 1.0),
+    ///         (0.0, 1.0 + 1e-9),
+    ///         (1.0 - 1e-9, 0.0),
+    ///         (1.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let buffer = buffer.weld_with(1e-6, |vertices| vertices[0]);
+    ///
+    /// assert_eq!(&[0usize, 1, 2, 2, 1, 3], buffer.as_index_slice());
+    /// assert_eq!(4, buffer.as_vertex_slice().len());
+    /// ```
+    ///
+    /// [`WeldVertices`]: crate::index::WeldVertices
+    #[must_use]
+    pub fn weld_with<F>(self, tolerance: Scalar<Position<G>>, f: F) -> Self
+    where
+        G: AsPosition,
+        Position<G>: EuclideanSpace,
+        Vector<Position<G>>: InnerSpace,
+        Scalar<Position<G>>: ToPrimitive,
+        F: FnMut(&[G]) -> G,
+    {
+        let MeshBuffer {
+            mut indices,
+            vertices,
+        } = self;
+        let vertices = weld_with(indices.iter_mut().collect(), vertices, tolerance, f);
+        MeshBuffer { indices, vertices }
+    }
 }
 
 impl<P, G> MeshBuffer<P, G>
@@ -545,6 +604,40 @@ where
         );
         Ok(())
     }
+
+    /// Welds vertices in a structured `MeshBuffer` with positions that are
+    /// within the given distance of each other and combines their data using
+    /// the given function.
+    ///
+    /// The index buffer is reindexed into the welded vertices and any vertices
+    /// that are not indexed are dropped. See [`WeldVertices`].
+    ///
+    /// [`WeldVertices`]: crate::index::WeldVertices
+    #[must_use]
+    pub fn weld_with<F>(self, tolerance: Scalar<Position<G>>, f: F) -> Self
+    where
+        P: Grouping<Group = P>,
+        G: AsPosition,
+        Position<G>: EuclideanSpace,
+        Vector<Position<G>>: InnerSpace,
+        Scalar<Position<G>>: ToPrimitive,
+        F: FnMut(&[G]) -> G,
+    {
+        let MeshBuffer {
+            mut indices,
+            vertices,
+        } = self;
+        let vertices = weld_with(
+            indices
+                .iter_mut()
+                .flat_map(|polygon| polygon.as_mut().iter_mut())
+                .collect(),
+            vertices,
+            tolerance,
+            f,
+        );
+        MeshBuffer { indices, vertices }
+    }
 }
 
 impl<P, Q, T, R, N, G> From<P> for MeshBuffer<R, G>
@@ -1147,19 +1240,58 @@ where
     }
 }
 
+// Welds vertex data and reindexes the given indices into the welded vertices.
+// Vertices that are not indexed are dropped.
+fn weld_with<N, G, F>(
+    indices: Vec<&mut N>,
+    vertices: Vec<G>,
+    tolerance: Scalar<Position<G>>,
+    f: F,
+) -> Vec<G>
+where
+    N: Copy + NumCast,
+    G: AsPosition,
+    Position<G>: EuclideanSpace,
+    Vector<Position<G>>: InnerSpace,
+    Scalar<Position<G>>: ToPrimitive,
+    F: FnMut(&[G]) -> G,
+{
+    // These conversions should never fail. Welded indices are never greater
+    // than the indices of their input vertices.
+    let into_usize = |index: N| <usize as NumCast>::from(index).expect("index overflow");
+    let mut is_indexed = vec![false; vertices.len()];
+    for index in indices.iter() {
+        is_indexed[into_usize(**index)] = true;
+    }
+    let (keys, vertices): (Vec<_>, Vec<_>) = vertices
+        .into_iter()
+        .enumerate()
+        .filter(|(key, _)| is_indexed[*key])
+        .unzip();
+    let (welds, vertices) = weld::weld_with(vertices, tolerance, f);
+    let mut remap = vec![0; is_indexed.len()];
+    for (key, weld) in keys.into_iter().zip(welds) {
+        remap[key] = weld;
+    }
+    for index in indices {
+        *index = <N as NumCast>::from(remap[into_usize(*index)]).expect("index overflow");
+    }
+    vertices
+}
+
 #[cfg(test)]
 mod tests {
     use decorum::N64;
-    use nalgebra::Point3;
+    use nalgebra::{Point3, Vector3};
 
-    use crate::buffer::{MeshBuffer, MeshBuffer4, MeshBufferN};
+    use crate::buffer::{MeshBuffer, MeshBuffer3, MeshBuffer4, MeshBufferN};
     use crate::graph::MeshGraph;
     use crate::index::Flat3;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
-    use crate::primitive::{BoundedPolygon, UnboundedPolygon};
+    use crate::primitive::{BoundedPolygon, Trigon, UnboundedPolygon};
 
     type E3 = Point3<N64>;
 
@@ -1213,6 +1345,48 @@ mod tests {
         assert_eq!(13, buffer.as_vertex_slice().len());
     }
 
+    #[test]
+    fn weld_flat_buffer() {
+        // Construct a triangle soup with noise in its positions.
+        let positions = UvSphere::new(8, 8)
+            .polygons::<Position<Point3<f64>>>()
+            .triangulate()
+            .vertices()
+            .enumerate()
+            .map(|(index, position)| position + Vector3::repeat(1e-9 * index as f64))
+            .collect::<Vec<_>>();
+        let buffer = MeshBuffer::<Flat3<usize>, Point3<f64>>::from_raw_buffers(
+            (0..positions.len()).collect::<Vec<_>>(),
+            positions,
+        )
+        .unwrap()
+        .weld_with(1e-6, |positions| positions[0]);
+
+        assert_eq!(336, buffer.as_index_slice().len());
+        assert_eq!(58, buffer.as_vertex_slice().len());
+    }
+
+    #[test]
+    fn weld_structured_buffer() {
+        let buffer = MeshBuffer3::<usize, Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(2, 4, 5)],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (9.0, 9.0, 9.0), // Not indexed.
+                (1.0, 1e-9, 0.0),
+                (1.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap()
+        .weld_with(1e-6, |positions| positions[0]);
+
+        assert_eq!(&[0usize, 1, 2], buffer.as_index_slice()[0].as_ref());
+        assert_eq!(&[2usize, 1, 3], buffer.as_index_slice()[1].as_ref());
+        assert_eq!(4, buffer.as_vertex_slice().len());
+    }
+
     #[test]
     fn convert_mesh_to_buffer_by_vertex() {
         let graph: MeshGraph<E3> = UvSphere::new(3, 2)
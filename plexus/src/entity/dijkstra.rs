@@ -2,6 +2,7 @@ use derivative::Derivative;
 use std::cmp::Reverse;
 use std::collections::hash_map::Entry;
 use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
 
 use crate::entity::storage::{AsStorage, Enumerate, Get};
 use crate::entity::traverse::Adjacency;
@@ -10,6 +11,7 @@ use crate::entity::EntityError;
 use crate::geometry::Metric;
 
 pub type MetricTree<K, Q> = HashMap<K, (Option<K>, Q)>;
+pub type Partition<K, Q> = HashMap<K, (K, Q)>;
 
 #[derive(Derivative)]
 #[derivative(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
@@ -81,6 +83,160 @@ where
     Ok(metrics)
 }
 
+/// Computes metrics from an entity toward another entity using A* search.
+///
+/// The heuristic estimates the metric from an entity to the destination. It
+/// must be _consistent_: it may never overestimate the metric of any path to
+/// the destination and may never decrease by more than the metric between
+/// adjacent entities. For example, the Euclidean distance to the position of
+/// the destination is consistent when metrics are Euclidean distances between
+/// positions. Search stops when the destination is reached, so the tree only
+/// includes the entities that have been explored.
+pub fn metrics_with_heuristic<'a, M, T, Q, F, H>(
+    from: T,
+    to: T::Key,
+    f: F,
+    h: H,
+) -> Result<MetricTree<T::Key, Q>, EntityError>
+where
+    M: 'a + AsStorage<T::Entity>,
+    T: Adjacency + Bind<&'a M> + Copy + Unbind<&'a M>,
+    Q: Copy + Metric,
+    F: Fn(T, T) -> Q,
+    H: Fn(T) -> Q,
+{
+    let (storage, from) = from.unbind();
+    if !storage.as_storage().contains_key(&to) {
+        return Err(EntityError::EntityNotFound);
+    }
+    let mut buffer = BinaryHeap::new();
+    let mut breadcrumbs = HashSet::new();
+    let mut metrics = HashMap::new();
+
+    let entity = T::bind(storage, from).ok_or(EntityError::EntityNotFound)?;
+    metrics.insert(from, (None, Q::zero()));
+    buffer.push(KeyedMetric(from, Reverse(h(entity))));
+    while let Some(KeyedMetric(key, _)) = buffer.pop() {
+        if key == to {
+            break;
+        }
+        if !breadcrumbs.insert(key) {
+            continue;
+        }
+        let entity = T::bind(storage, key).ok_or(EntityError::EntityNotFound)?;
+        let (_, metric) = metrics[&key];
+        for adjacent in entity
+            .adjacency()
+            .into_iter()
+            .map(|key| T::bind(storage, key))
+        {
+            let adjacent = adjacent.ok_or(EntityError::EntityNotFound)?;
+            if breadcrumbs.contains(&adjacent.key()) {
+                continue;
+            }
+            let metric = metric + summand(f(entity, adjacent))?;
+            if relax(&mut metrics, adjacent.key(), (Some(key), metric)) {
+                buffer.push(KeyedMetric(adjacent.key(), Reverse(metric + h(adjacent))));
+            }
+        }
+    }
+    Ok(metrics)
+}
+
+/// Partitions entities by their nearest seed.
+///
+/// Computes metrics from all seeds at once and maps each reachable entity to
+/// the seed with the least metric to that entity along with that metric. This
+/// forms a Voronoi partition of the entities. Entities that are equidistant
+/// from more than one seed are assigned to the seed that reaches them first.
+pub fn partition_with<'a, M, T, Q, F, I>(
+    seeds: I,
+    f: F,
+) -> Result<Partition<T::Key, Q>, EntityError>
+where
+    M: 'a + AsStorage<T::Entity>,
+    T: Adjacency + Bind<&'a M> + Copy + Unbind<&'a M>,
+    Q: Copy + Metric,
+    F: Fn(T, T) -> Q,
+    I: IntoIterator<Item = T>,
+{
+    let mut buffer = BinaryHeap::new();
+    let mut breadcrumbs = HashSet::new();
+    let mut partition = HashMap::new();
+
+    let mut storage = None;
+    for seed in seeds {
+        let (target, key) = seed.unbind();
+        storage = Some(target);
+        partition.insert(key, (key, Q::zero()));
+        buffer.push(KeyedMetric(key, Reverse(Q::zero())));
+    }
+    let storage = match storage {
+        Some(storage) => storage,
+        _ => {
+            return Ok(partition);
+        }
+    };
+    while let Some(KeyedMetric(key, _)) = buffer.pop() {
+        if !breadcrumbs.insert(key) {
+            continue;
+        }
+        let entity = T::bind(storage, key).ok_or(EntityError::EntityNotFound)?;
+        let (seed, metric) = partition[&key];
+        for adjacent in entity
+            .adjacency()
+            .into_iter()
+            .map(|key| T::bind(storage, key))
+        {
+            let adjacent = adjacent.ok_or(EntityError::EntityNotFound)?;
+            if breadcrumbs.contains(&adjacent.key()) {
+                continue;
+            }
+            let metric = metric + summand(f(entity, adjacent))?;
+            if relax(&mut partition, adjacent.key(), (seed, metric)) {
+                buffer.push(KeyedMetric(adjacent.key(), Reverse(metric)));
+            }
+        }
+    }
+    Ok(partition)
+}
+
+fn summand<Q>(summand: Q) -> Result<Q, EntityError>
+where
+    Q: Metric,
+{
+    if summand < Q::zero() {
+        Err(EntityError::Data)
+    }
+    else {
+        Ok(summand)
+    }
+}
+
+// Inserts or replaces the value of an entity if its metric is less than the
+// metric of any existing value. Returns `true` if the value has been written.
+fn relax<K, T, Q>(metrics: &mut HashMap<K, (T, Q)>, key: K, value: (T, Q)) -> bool
+where
+    K: Eq + Hash,
+    Q: Metric,
+{
+    match metrics.entry(key) {
+        Entry::Occupied(mut entry) => {
+            if value.1 < entry.get().1 {
+                entry.insert(value);
+                true
+            }
+            else {
+                false
+            }
+        }
+        Entry::Vacant(entry) => {
+            entry.insert(value);
+            true
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use decorum::R64;
@@ -156,4 +312,45 @@ mod tests {
         assert_eq!(cq, (Some(b), 4.0.into()));
         assert_eq!(dq, (Some(a), 2.0.into()));
     }
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn euclidean_distance_heuristic_metrics() {
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            vec![(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)],
+        )
+        .unwrap();
+        let vertex = graph.vertices().nth(0).unwrap();
+        let c = vertex.outgoing_arc().next_arc().destination_vertex();
+        let metrics = dijkstra::metrics_with_heuristic(
+            vertex,
+            c.key(),
+            |from, to| R64::from((to.position() - from.position()).magnitude()),
+            |vertex| R64::from((c.position() - vertex.position()).magnitude()),
+        )
+        .unwrap();
+        let (previous, cq) = *metrics.get(&c.key()).unwrap();
+
+        assert!(previous.is_some());
+        assert_eq!(cq, 4.0.into());
+    }
+
+    #[test]
+    fn logical_partition() {
+        let graph =
+            MeshGraph::<()>::from_raw_buffers(vec![Tetragon::new(0usize, 1, 2, 3)], vec![(); 4])
+                .unwrap();
+        let vertex = graph.vertices().nth(0).unwrap();
+        let a = vertex.key();
+        let b = vertex.outgoing_arc().destination_vertex();
+        let c = vertex.outgoing_arc().next_arc().destination_vertex().key();
+        let partition = dijkstra::partition_with(vec![vertex, b], |_, _| 1usize).unwrap();
+        let b = b.key();
+
+        assert_eq!(4, partition.len());
+        assert_eq!(*partition.get(&a).unwrap(), (a, 0));
+        assert_eq!(*partition.get(&b).unwrap(), (b, 0));
+        assert_eq!(*partition.get(&c).unwrap(), (b, 1));
+    }
 }
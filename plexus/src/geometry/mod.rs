@@ -12,6 +12,9 @@
 use num::{One, Zero};
 
 pub mod partition;
+pub(crate) mod patch;
+pub(crate) mod triangulation;
+pub(crate) mod weld;
 
 pub use theon::query::*;
 pub use theon::space::{Scalar, Vector};
@@ -0,0 +1,269 @@
+//! Triangulated patches that span polygons.
+
+use decorum::Real;
+use num::{One, Zero};
+use std::collections::HashMap;
+use std::mem;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+
+use crate::geometry::triangulation;
+use crate::IteratorExt as _;
+
+// Refinement inserts at most this many points per point in the perimeter of a
+// patch. This guarantees termination for degenerate perimeters.
+const REFINEMENT_POINT_LIMIT: usize = 16;
+// Fairing relaxes interior points iteratively and converges slowly for large
+// patches, so this only approximates a fair patch.
+const FAIRING_ITERATION_COUNT: usize = 64;
+
+/// Triangulated patch that spans a polygon in $\Reals^n$.
+///
+/// The first points of a patch form the perimeter of the polygon and are never
+/// moved. Any remaining points are interior points that have been inserted by
+/// refinement.
+pub struct Patch<S>
+where
+    S: EuclideanSpace,
+{
+    pub points: Vec<S>,
+    pub triangles: Vec<[usize; 3]>,
+    arity: usize,
+}
+
+impl<S> Patch<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+{
+    /// Triangulates a polygon such that the total area of its triangles is
+    /// minimized.
+    ///
+    /// As with [`triangulation::minimum_weight_with`], the given predicate
+    /// must accept any diagonal between points of the polygon.
+    ///
+    /// Returns `None` if no triangulation can be formed from the accepted
+    /// diagonals.
+    pub fn minimum_area_with<F>(points: Vec<S>, f: F) -> Option<Self>
+    where
+        F: FnMut(usize, usize) -> bool,
+    {
+        let triangles = triangulation::minimum_weight_with(
+            points.len(),
+            |a, b, c| area(points[a], points[b], points[c]),
+            f,
+        )?;
+        Some(Patch {
+            arity: points.len(),
+            points,
+            triangles,
+        })
+    }
+
+    /// Refines the patch such that the density of its points approximates the
+    /// density of the points in its perimeter.
+    ///
+    /// Each point in the perimeter is assigned a scale that is the mean length
+    /// of its edges in the perimeter. Triangles are split at their centroids
+    /// when the centroid is far from each of their vertices relative to these
+    /// scales (à la Liepa) and interior edges are then flipped to improve the
+    /// shape of triangles. As with [`Patch::minimum_area_with`], the given
+    /// predicate must accept any diagonal between points of the perimeter.
+    pub fn refine_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, usize) -> bool,
+    {
+        let n = self.arity;
+        let one: Scalar<S> = One::one();
+        let (two, three) = (one + one, one + one + one);
+        let mut scales = (0..n)
+            .map(|index| {
+                let p = self.points[index];
+                let (a, b) = (
+                    self.points[(index + n - 1) % n],
+                    self.points[(index + 1) % n],
+                );
+                ((p - a).magnitude() + (p - b).magnitude()) / two
+            })
+            .collect::<Vec<_>>();
+        loop {
+            let mut is_split = false;
+            for [a, b, c] in mem::take(&mut self.triangles) {
+                let centroid = S::centroid([a, b, c].map(|index| self.points[index]));
+                let scale = (scales[a] + scales[b] + scales[c]) / three;
+                // Split the triangle if $\sqrt{2}$ times the distance from its
+                // centroid to each of its vertices exceeds both the scale of
+                // the centroid and the scale of that vertex.
+                let is_coarse = |centroid: S| {
+                    [a, b, c].iter().all(|&index| {
+                        let offset = centroid - self.points[index];
+                        let distance = offset.dot(offset);
+                        distance + distance > scale * scale
+                            && distance + distance > scales[index] * scales[index]
+                    })
+                };
+                match centroid {
+                    Some(centroid)
+                        if self.points.len() < n + (n * REFINEMENT_POINT_LIMIT)
+                            && is_coarse(centroid) =>
+                    {
+                        let d = self.points.len();
+                        self.points.push(centroid);
+                        scales.push(scale);
+                        self.triangles.extend([[a, b, d], [b, c, d], [c, a, d]]);
+                        is_split = true;
+                    }
+                    _ => {
+                        self.triangles.push([a, b, c]);
+                    }
+                }
+            }
+            self.relax_with(&mut f);
+            if !is_split {
+                break;
+            }
+        }
+    }
+
+    /// Fairs the patch by moving each interior point toward the centroid of
+    /// its neighbors. The perimeter is not moved.
+    ///
+    /// This approximates a membrane that spans the perimeter and so blends the
+    /// patch with its perimeter.
+    pub fn fair(&mut self) {
+        let n = self.arity;
+        if self.points.len() == n {
+            return;
+        }
+        let mut neighbors = vec![vec![]; self.points.len()];
+        for triangle in self.triangles.iter() {
+            for (a, b) in triangle.iter().cloned().perimeter() {
+                neighbors[a].push(b);
+                neighbors[b].push(a);
+            }
+        }
+        for neighbors in neighbors.iter_mut() {
+            neighbors.sort_unstable();
+            neighbors.dedup();
+        }
+        for _ in 0..FAIRING_ITERATION_COUNT {
+            self.points = self
+                .points
+                .iter()
+                .enumerate()
+                .map(|(index, point)| {
+                    if index < n {
+                        *point
+                    }
+                    else {
+                        S::centroid(neighbors[index].iter().map(|&index| self.points[index]))
+                            .unwrap_or(*point)
+                    }
+                })
+                .collect();
+        }
+    }
+
+    /// Flips interior edges that are opposite angles with a sum that exceeds
+    /// $\pi$.
+    fn relax_with<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(usize, usize) -> bool,
+    {
+        let n = self.arity;
+        let apex = |triangle: &[usize; 3], a: usize, b: usize| {
+            triangle
+                .iter()
+                .cloned()
+                .find(|&index| index != a && index != b)
+        };
+        // Flips are bounded to guarantee termination, though this should only
+        // be reached in degenerate cases.
+        let limit = self.triangles.len() * self.triangles.len();
+        for _ in 0..limit {
+            let arcs = self
+                .triangles
+                .iter()
+                .enumerate()
+                .flat_map(|(index, triangle)| {
+                    triangle
+                        .iter()
+                        .cloned()
+                        .perimeter()
+                        .map(move |arc| (arc, index))
+                })
+                .collect::<Vec<_>>();
+            let triangles = arcs.iter().cloned().collect::<HashMap<_, _>>();
+            let flip = arcs.iter().cloned().find_map(|((a, b), left)| {
+                let right = *triangles.get(&(b, a))?;
+                let c = apex(&self.triangles[left], a, b)?;
+                let d = apex(&self.triangles[right], a, b)?;
+                // The flipped edge must not already exist in the patch nor
+                // form a rejected diagonal between points in the
+                // perimeter.
+                if triangles.contains_key(&(c, d))
+                    || triangles.contains_key(&(d, c))
+                    || (c < n && d < n && !f(c, d))
+                {
+                    return None;
+                }
+                let [p, q, r, s] = [a, b, c, d].map(|index| self.points[index]);
+                if is_delaunay(p, q, r, s) {
+                    None
+                }
+                else {
+                    Some((left, right, [a, b, c, d]))
+                }
+            });
+            if let Some((left, right, [a, b, c, d])) = flip {
+                self.triangles[left] = [c, a, d];
+                self.triangles[right] = [d, b, c];
+            }
+            else {
+                break;
+            }
+        }
+    }
+}
+
+/// Determines if the edge $\overline{AB}$ shared by the triangles
+/// $\triangle{ABC}$ and $\triangle{BAD}$ is locally Delaunay. This is the case
+/// if the sum of the angles opposite the edge does not exceed $\pi$.
+fn is_delaunay<S>(a: S, b: S, c: S, d: S) -> bool
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+{
+    // The sum of the angles exceeds $\pi$ if and only if the sum of their
+    // cotangents is negative. The cotangent of the angle between $u$ and $v$
+    // is $\frac{u \cdot v}{\lvert u \times v \rvert}$, so the sum is scaled by
+    // the (non-negative) product of the denominators.
+    let (uc, vc) = (a - c, b - c);
+    let (ud, vd) = (a - d, b - d);
+    let (dc, dd) = (uc.dot(vc), ud.dot(vd));
+    (dc * cross_magnitude::<S>(ud, vd)) + (dd * cross_magnitude::<S>(uc, vc)) >= Zero::zero()
+}
+
+fn area<S>(a: S, b: S, c: S) -> Scalar<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+{
+    cross_magnitude::<S>(b - a, c - a)
+}
+
+/// Computes the magnitude of the cross product of two vectors in any number
+/// of dimensions via Lagrange's identity.
+pub(crate) fn cross_magnitude<S>(u: Vector<S>, v: Vector<S>) -> Scalar<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+{
+    let uv = u.dot(v);
+    let x = (u.dot(u) * v.dot(v)) - (uv * uv);
+    if x > Zero::zero() {
+        x.sqrt()
+    }
+    else {
+        Zero::zero()
+    }
+}
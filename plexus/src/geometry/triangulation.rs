@@ -0,0 +1,647 @@
+//! Geometric triangulation of polygons.
+
+use decorum::Real;
+use num::{Signed, Zero};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+
+use crate::IteratorExt as _;
+
+// Power iteration converges quickly for the covariance of a polygon, because
+// the dominant eigenvalues are typically well separated from the eigenvalue
+// associated with the normal of the polygon.
+const POWER_ITERATION_COUNT: usize = 16;
+// Refinement inserts at most this many Steiner points per point in the input
+// polygon. This guarantees termination when the minimum angle is too large to
+// be satisfied.
+const STEINER_POINT_LIMIT: usize = 16;
+
+/// Projection onto the best-fit plane of a set of points.
+///
+/// The plane passes through the centroid of the points and is spanned by the
+/// two principal axes of their covariance. These axes are approximated via
+/// power iteration, which only requires inner products and so does not depend
+/// on the dimensionality of the space.
+#[derive(Clone, Copy)]
+pub struct PlaneProjection<S>
+where
+    S: EuclideanSpace,
+{
+    origin: S,
+    basis: (Vector<S>, Vector<S>),
+}
+
+impl<S> PlaneProjection<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+{
+    /// Computes the projection for the given points.
+    ///
+    /// Returns `None` if the points are converged or collinear.
+    pub fn from_points(points: &[S]) -> Option<Self> {
+        let origin = S::centroid(points.iter().cloned())?;
+        let offsets = points
+            .iter()
+            .map(|point| *point - origin)
+            .collect::<Vec<_>>();
+        // Rejects the component of a vector along an axis, if any.
+        let reject = |x: Vector<S>, axis: Option<Vector<S>>| match axis {
+            Some(axis) => x - (axis * axis.dot(x)),
+            _ => x,
+        };
+        // Applies the (unnormalized) covariance of the offsets to a vector.
+        let covary = |x: Vector<S>| {
+            offsets.iter().fold(Zero::zero(), |sum: Vector<S>, offset| {
+                sum + (*offset * offset.dot(x))
+            })
+        };
+        let principal = |axis: Option<Vector<S>>| {
+            // Seed the iteration with the offset that is farthest from the
+            // axis.
+            let seed = offsets
+                .iter()
+                .map(|offset| reject(*offset, axis))
+                .max_by(|a, b| {
+                    a.magnitude()
+                        .partial_cmp(&b.magnitude())
+                        .unwrap_or(Ordering::Equal)
+                })?
+                .normalize()?;
+            (0..POWER_ITERATION_COUNT).try_fold(seed, |x, _| reject(covary(x), axis).normalize())
+        };
+        let u = principal(None)?;
+        let v = principal(Some(u))?;
+        Some(PlaneProjection {
+            origin,
+            basis: (u, v),
+        })
+    }
+
+    /// Projects a point into the coordinates of the plane.
+    pub fn project(&self, point: S) -> (Scalar<S>, Scalar<S>) {
+        let offset = point - self.origin;
+        (offset.dot(self.basis.0), offset.dot(self.basis.1))
+    }
+
+    /// Embeds coordinates of the plane into the space.
+    pub fn embed(&self, (x, y): (Scalar<S>, Scalar<S>)) -> S {
+        self.origin + (self.basis.0 * x) + (self.basis.1 * y)
+    }
+}
+
+/// Constrained Delaunay triangulation of a polygon.
+///
+/// Points beyond those of the input polygon are Steiner points that have been
+/// inserted into the interior of the polygon.
+pub struct Triangulation<T> {
+    pub points: Vec<(T, T)>,
+    pub triangles: Vec<[usize; 3]>,
+}
+
+/// Triangulates a simple polygon via ear clipping.
+///
+/// The polygon is given by its points in $\Reals^2$ and may have either
+/// winding. An ear is only clipped if the given predicate accepts its
+/// diagonal, which is given as the indices of its endpoints. This can be used
+/// to reject diagonals that would otherwise conflict with topology.
+///
+/// Returns triangles as indices into the points with the same winding as the
+/// polygon or `None` if no ear can be found, such as when the polygon is
+/// degenerate or intersects itself.
+pub fn ear_clip_with<T, F>(points: &[(T, T)], mut f: F) -> Option<Vec<[usize; 3]>>
+where
+    T: Copy + PartialOrd + Signed,
+    F: FnMut(usize, usize) -> bool,
+{
+    if points.len() < 3 {
+        return None;
+    }
+    let area = area(points);
+    if area.is_zero() {
+        return None;
+    }
+    // Computes the determinant of the triangle formed by the given points
+    // relative to the winding of the polygon. This is positive if the points
+    // turn in the same direction as the polygon.
+    let turn = |a: usize, b: usize, c: usize| {
+        let determinant = determinant(points[a], points[b], points[c]);
+        if area.is_negative() {
+            -determinant
+        }
+        else {
+            determinant
+        }
+    };
+    let mut indices = (0..points.len()).collect::<Vec<_>>();
+    let mut triangles = Vec::with_capacity(points.len() - 2);
+    while indices.len() > 3 {
+        let n = indices.len();
+        let triangle = |i: usize| (indices[(i + n - 1) % n], indices[i], indices[(i + 1) % n]);
+        // Determines if any other vertex of the remaining polygon lies within
+        // or on the triangle formed about the vertex at the given position.
+        let is_occluded = |i: usize| {
+            let (a, b, c) = triangle(i);
+            indices
+                .iter()
+                .cloned()
+                .filter(|&d| d != a && d != b && d != c)
+                .any(|d| {
+                    !turn(a, b, d).is_negative()
+                        && !turn(b, c, d).is_negative()
+                        && !turn(c, a, d).is_negative()
+                })
+        };
+        // Prefer strictly convex ears. Collinear vertices are only clipped if
+        // no such ear exists, which yields degenerate triangles.
+        let i = (0..n)
+            .find(|&i| {
+                let (a, b, c) = triangle(i);
+                turn(a, b, c).is_positive() && !is_occluded(i) && f(a, c)
+            })
+            .or_else(|| {
+                (0..n).find(|&i| {
+                    let (a, b, c) = triangle(i);
+                    turn(a, b, c).is_zero() && !is_occluded(i) && f(a, c)
+                })
+            })?;
+        let (a, b, c) = triangle(i);
+        triangles.push([a, b, c]);
+        indices.remove(i);
+    }
+    triangles.push([indices[0], indices[1], indices[2]]);
+    Some(triangles)
+}
+
+/// Computes the constrained Delaunay triangulation of a simple polygon.
+///
+/// The polygon is given by its points in $\Reals^2$ and may have either
+/// winding. The edges of the polygon constrain the triangulation. The polygon
+/// is first triangulated via ear clipping and then edges are flipped until all
+/// unconstrained edges are locally Delaunay. As with [`ear_clip_with`], the
+/// given predicate must accept any diagonal between points of the polygon.
+///
+/// If a minimum angle (in radians) is given, then the triangulation is refined
+/// by inserting Steiner points at the circumcenters of triangles with smaller
+/// angles (à la Ruppert). Steiner points are only inserted into the interior
+/// of the polygon, so its perimeter is never split. Triangles with
+/// circumcenters that encroach upon the perimeter are not refined and the
+/// number of Steiner points is bounded, so the minimum angle is not
+/// guaranteed.
+///
+/// Returns `None` if the polygon cannot be triangulated.
+pub fn delaunay_with<T, F>(
+    points: &[(T, T)],
+    angle: Option<T>,
+    mut f: F,
+) -> Option<Triangulation<T>>
+where
+    T: Copy + PartialOrd + Real + Signed,
+    F: FnMut(usize, usize) -> bool,
+{
+    let triangles = ear_clip_with(points, &mut f)?;
+    // Mirror clockwise polygons so that all triangles are counter-clockwise.
+    let is_mirrored = area(points).is_negative();
+    let mirror = |(x, y): (T, T)| if is_mirrored { (x, -y) } else { (x, y) };
+    let mut mesh = Mesh {
+        points: points.iter().cloned().map(mirror).collect(),
+        triangles: Vec::with_capacity(triangles.len()),
+        arcs: HashMap::with_capacity(triangles.len() * 3),
+        n: points.len(),
+        f,
+    };
+    for triangle in triangles {
+        mesh.replace(&[(mesh.triangles.len(), triangle)]);
+    }
+    let diagonals = mesh
+        .triangles
+        .iter()
+        .flat_map(|triangle| triangle.iter().cloned().perimeter())
+        .filter(|&(a, b)| !mesh.is_constrained(a, b))
+        .collect();
+    mesh.legalize(diagonals);
+    if let Some(angle) = angle {
+        mesh.refine(angle);
+    }
+    let Mesh {
+        points, triangles, ..
+    } = mesh;
+    Some(Triangulation {
+        points: points.into_iter().map(mirror).collect(),
+        triangles,
+    })
+}
+
+/// Computes a minimum-weight triangulation of a polygon.
+///
+/// The polygon is given by its number of points and the weight of each
+/// triangle (given as indices into the points) is computed by the given
+/// function. The triangulation minimizes the sum of the weights of its
+/// triangles via dynamic programming. As with [`ear_clip_with`], the given
+/// predicate must accept any diagonal between points of the polygon.
+///
+/// Returns triangles as indices into the points with the same winding as the
+/// polygon or `None` if no triangulation can be formed from the accepted
+/// diagonals.
+pub fn minimum_weight_with<T, W, F>(n: usize, mut weight: W, mut f: F) -> Option<Vec<[usize; 3]>>
+where
+    T: Copy + PartialOrd + Zero,
+    W: FnMut(usize, usize, usize) -> T,
+    F: FnMut(usize, usize) -> bool,
+{
+    if n < 3 {
+        return None;
+    }
+    let is_accepted = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| i < j && (j == i + 1 || (i == 0 && j == n - 1) || f(i, j)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    // The minimum weight and apex of the sub-polygon between any two points
+    // `i < j`, if any. Edges of the polygon form null sub-polygons.
+    let mut weights = vec![vec![None::<(T, usize)>; n]; n];
+    for (i, row) in weights.iter_mut().enumerate().take(n - 1) {
+        row[i + 1] = Some((T::zero(), i));
+    }
+    for length in 2..n {
+        for i in 0..(n - length) {
+            let j = i + length;
+            if !is_accepted[i][j] {
+                continue;
+            }
+            let mut minimum = None;
+            for k in (i + 1)..j {
+                if let (Some((a, _)), Some((b, _))) = (weights[i][k], weights[k][j]) {
+                    let w = a + b + weight(i, k, j);
+                    if minimum.map_or(true, |(minimum, _)| w < minimum) {
+                        minimum = Some((w, k));
+                    }
+                }
+            }
+            weights[i][j] = minimum;
+        }
+    }
+    weights[0][n - 1]?;
+    let mut triangles = Vec::with_capacity(n - 2);
+    let mut polygons = vec![(0, n - 1)];
+    while let Some((i, j)) = polygons.pop() {
+        if j > i + 1 {
+            let (_, k) = weights[i][j]?;
+            triangles.push([i, k, j]);
+            polygons.push((i, k));
+            polygons.push((k, j));
+        }
+    }
+    Some(triangles)
+}
+
+// Counter-clockwise triangle mesh with a constrained perimeter. The first `n`
+// points form the perimeter.
+struct Mesh<T, F> {
+    points: Vec<(T, T)>,
+    triangles: Vec<[usize; 3]>,
+    arcs: HashMap<(usize, usize), usize>,
+    n: usize,
+    f: F,
+}
+
+impl<T, F> Mesh<T, F>
+where
+    T: Copy + PartialOrd + Real + Signed,
+    F: FnMut(usize, usize) -> bool,
+{
+    fn is_constrained(&self, a: usize, b: usize) -> bool {
+        let n = self.n;
+        a < n && b < n && ((a + 1) % n == b || (b + 1) % n == a)
+    }
+
+    // Gets the vertex of the triangle opposite the arc from `a` to `b`.
+    fn apex(&self, a: usize, b: usize) -> Option<usize> {
+        self.arcs.get(&(a, b)).and_then(|&index| {
+            self.triangles[index]
+                .iter()
+                .cloned()
+                .find(|&c| c != a && c != b)
+        })
+    }
+
+    // Replaces triangles by index or pushes triangles if the index is out of
+    // bounds. All replaced triangles are disconnected before any triangles are
+    // connected, because replacements may exchange arcs.
+    fn replace(&mut self, triangles: &[(usize, [usize; 3])]) {
+        for &(index, _) in triangles {
+            if let Some(triangle) = self.triangles.get(index) {
+                for arc in triangle.iter().cloned().perimeter() {
+                    self.arcs.remove(&arc);
+                }
+            }
+        }
+        for &(index, triangle) in triangles {
+            if index < self.triangles.len() {
+                self.triangles[index] = triangle;
+            }
+            else {
+                self.triangles.push(triangle);
+            }
+            for arc in triangle.iter().cloned().perimeter() {
+                self.arcs.insert(arc, index);
+            }
+        }
+    }
+
+    // Flips unconstrained edges until they are locally Delaunay.
+    fn legalize(&mut self, mut arcs: Vec<(usize, usize)>) {
+        // Flipping terminates after a quadratic number of flips, but rounding
+        // errors may cause cycles among (nearly) cocircular points.
+        let mut limit = self.points.len() * self.points.len();
+        while let Some((a, b)) = arcs.pop() {
+            if limit == 0 {
+                break;
+            }
+            if self.is_constrained(a, b) {
+                continue;
+            }
+            let (c, d) = match (self.apex(a, b), self.apex(b, a)) {
+                (Some(c), Some(d)) => (c, d),
+                _ => continue,
+            };
+            let [pa, pb, pc, pd] = [a, b, c, d].map(|index| self.points[index]);
+            if !is_in_circumcircle(pa, pb, pc, pd)
+                || !determinant(pc, pa, pd).is_positive()
+                || !determinant(pd, pb, pc).is_positive()
+            {
+                continue;
+            }
+            if c < self.n && d < self.n && !(self.f)(c, d) {
+                continue;
+            }
+            let (ab, ba) = (self.arcs[&(a, b)], self.arcs[&(b, a)]);
+            self.replace(&[(ab, [c, a, d]), (ba, [d, b, c])]);
+            limit -= 1;
+            arcs.extend([(c, a), (a, d), (d, b), (b, c)]);
+        }
+    }
+
+    fn insert(&mut self, index: usize, point: (T, T)) {
+        let [a, b, c] = self.triangles[index];
+        let d = self.points.len();
+        self.points.push(point);
+        let n = self.triangles.len();
+        self.replace(&[(index, [a, b, d]), (n, [b, c, d]), (n + 1, [c, a, d])]);
+        self.legalize(vec![(a, b), (b, c), (c, a)]);
+    }
+
+    fn locate(&self, point: (T, T)) -> Option<usize> {
+        self.triangles.iter().position(|&[a, b, c]| {
+            let [a, b, c] = [a, b, c].map(|index| self.points[index]);
+            determinant(a, b, point).is_positive()
+                && determinant(b, c, point).is_positive()
+                && determinant(c, a, point).is_positive()
+        })
+    }
+
+    fn refine(&mut self, angle: T) {
+        let two = T::one() + T::one();
+        // A triangle is skinny if its shortest edge $l$ and circumradius $r$
+        // satisfy $l < 2r\sin\theta$ for the minimum angle $\theta$.
+        let bound = {
+            let sine = angle.sin();
+            two * two * sine * sine
+        };
+        let limit = self.n + (self.n * STEINER_POINT_LIMIT);
+        let mut skipped = HashSet::new();
+        while self.points.len() < limit {
+            let skinny = self.triangles.iter().cloned().find_map(|triangle| {
+                if skipped.contains(&triangle) {
+                    return None;
+                }
+                let [a, b, c] = triangle.map(|index| self.points[index]);
+                let center = circumcenter(a, b, c)?;
+                let radius = square_distance(center, a);
+                let edges = [
+                    square_distance(a, b),
+                    square_distance(b, c),
+                    square_distance(c, a),
+                ];
+                let edge = edges.iter().cloned().fold(edges[0], |min, edge| {
+                    if edge < min {
+                        edge
+                    }
+                    else {
+                        min
+                    }
+                });
+                if edge < bound * radius {
+                    Some((triangle, center))
+                }
+                else {
+                    None
+                }
+            });
+            let (triangle, center) = match skinny {
+                Some(skinny) => skinny,
+                _ => break,
+            };
+            // Do not insert circumcenters that lie within the diametral circle
+            // of a perimeter edge, because the perimeter is never split.
+            let is_encroaching = (0..self.n).any(|a| {
+                let b = (a + 1) % self.n;
+                let (pa, pb) = (self.points[a], self.points[b]);
+                let (ax, ay) = (pa.0 - center.0, pa.1 - center.1);
+                let (bx, by) = (pb.0 - center.0, pb.1 - center.1);
+                ((ax * bx) + (ay * by)).is_negative()
+            });
+            match self.locate(center) {
+                Some(index) if !is_encroaching => {
+                    self.insert(index, center);
+                }
+                _ => {
+                    skipped.insert(triangle);
+                }
+            }
+        }
+    }
+}
+
+/// Computes twice the signed area of a polygon in $\Reals^2$.
+///
+/// The area is positive if the polygon winds counter-clockwise.
+pub fn area<T>(points: &[(T, T)]) -> T
+where
+    T: Copy + Signed,
+{
+    points
+        .iter()
+        .perimeter()
+        .fold(T::zero(), |area, (a, b)| area + ((a.0 * b.1) - (b.0 * a.1)))
+}
+
+// Computes the determinant of a matrix formed from the given points. This is
+// positive if the points turn counter-clockwise.
+fn determinant<T>(a: (T, T), b: (T, T), c: (T, T)) -> T
+where
+    T: Copy + Signed,
+{
+    ((b.0 - a.0) * (c.1 - a.1)) - ((b.1 - a.1) * (c.0 - a.0))
+}
+
+// Determines if `d` lies strictly within the circumcircle of the
+// counter-clockwise triangle formed by `a`, `b`, and `c`.
+fn is_in_circumcircle<T>(a: (T, T), b: (T, T), c: (T, T), d: (T, T)) -> bool
+where
+    T: Copy + Signed,
+{
+    let (ax, ay) = (a.0 - d.0, a.1 - d.1);
+    let (bx, by) = (b.0 - d.0, b.1 - d.1);
+    let (cx, cy) = (c.0 - d.0, c.1 - d.1);
+    let determinant = (((ax * ax) + (ay * ay)) * ((bx * cy) - (cx * by)))
+        - (((bx * bx) + (by * by)) * ((ax * cy) - (cx * ay)))
+        + (((cx * cx) + (cy * cy)) * ((ax * by) - (bx * ay)));
+    determinant.is_positive()
+}
+
+fn circumcenter<T>(a: (T, T), b: (T, T), c: (T, T)) -> Option<(T, T)>
+where
+    T: Copy + Signed,
+{
+    let (bx, by) = (b.0 - a.0, b.1 - a.1);
+    let (cx, cy) = (c.0 - a.0, c.1 - a.1);
+    let d = ((bx * cy) - (by * cx)) * (T::one() + T::one());
+    if d.is_zero() {
+        return None;
+    }
+    let (b2, c2) = ((bx * bx) + (by * by), (cx * cx) + (cy * cy));
+    Some((
+        a.0 + (((cy * b2) - (by * c2)) / d),
+        a.1 + (((bx * c2) - (cx * b2)) / d),
+    ))
+}
+
+fn square_distance<T>(a: (T, T), b: (T, T)) -> T
+where
+    T: Copy + Signed,
+{
+    let (x, y) = (a.0 - b.0, a.1 - b.1);
+    (x * x) + (y * y)
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::geometry::triangulation::{self, PlaneProjection};
+
+    fn area(points: &[(f64, f64)], [a, b, c]: [usize; 3]) -> f64 {
+        let (a, b, c) = (points[a], points[b], points[c]);
+        (((b.0 - a.0) * (c.1 - a.1)) - ((b.1 - a.1) * (c.0 - a.0))) / 2.0
+    }
+
+    #[test]
+    fn ear_clip_concave_polygon() {
+        // An L-shaped hexagon with a reflex vertex at `(1, 1)`.
+        let points = [
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (0.0, 2.0),
+        ];
+        let triangles = triangulation::ear_clip_with(&points, |_, _| true).unwrap();
+
+        assert_eq!(4, triangles.len());
+        assert!(triangles
+            .iter()
+            .all(|triangle| area(&points, *triangle) > 0.0));
+        assert_eq!(
+            3.0,
+            triangles
+                .iter()
+                .map(|triangle| area(&points, *triangle))
+                .sum::<f64>()
+        );
+    }
+
+    #[test]
+    fn ear_clip_with_rejected_diagonals() {
+        let points = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+
+        assert!(triangulation::ear_clip_with(&points, |_, _| false).is_none());
+    }
+
+    #[test]
+    fn minimum_weight_with_rejected_diagonals() {
+        // A long hexagon with points along the x-axis at `y = 0` and `y = 1`.
+        let points = [
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (8.0, 0.0),
+            (8.0, 1.0),
+            (4.0, 1.0),
+            (0.0, 1.0),
+        ];
+        let weight = |a: usize, b: usize, c: usize| area(&points, [a, b, c]).abs();
+        let triangles = triangulation::minimum_weight_with(6, weight, |_, _| true).unwrap();
+
+        assert_eq!(4, triangles.len());
+        let total: f64 = triangles
+            .iter()
+            .map(|triangle| area(&points, *triangle))
+            .sum();
+        assert_eq!(8.0, total);
+        // All diagonals from the vertex at `(0, 0)` are rejected.
+        let triangles =
+            triangulation::minimum_weight_with(6, weight, |a, b| a != 0 && b != 0).unwrap();
+        assert!(triangles
+            .iter()
+            .all(|triangle| !triangle.contains(&0)
+                || (triangle.contains(&1) && triangle.contains(&5))));
+        // No triangulation exists if all diagonals are rejected.
+        assert!(triangulation::minimum_weight_with(6, weight, |_, _| false).is_none());
+    }
+
+    #[test]
+    fn delaunay_flip_diagonal() {
+        // Ear clipping splits this quadrilateral along its longer diagonal.
+        let points = [(2.0, -0.5), (4.0, 0.0), (2.0, 0.5), (0.0, 0.0)];
+        let triangulation = triangulation::delaunay_with(&points, None, |_, _| true).unwrap();
+
+        assert_eq!(4, triangulation.points.len());
+        assert!(triangulation
+            .triangles
+            .iter()
+            .all(|triangle| triangle.contains(&0) && triangle.contains(&2)));
+    }
+
+    #[test]
+    fn delaunay_refine_circle() {
+        let points = (0..32)
+            .map(|n| {
+                let theta = f64::from(n) * std::f64::consts::PI / 16.0;
+                (theta.cos(), theta.sin())
+            })
+            .collect::<Vec<_>>();
+        let triangulation =
+            triangulation::delaunay_with(&points, Some(20.0f64.to_radians()), |_, _| true).unwrap();
+
+        assert!(triangulation.points.len() > points.len());
+        assert!(triangulation
+            .triangles
+            .iter()
+            .all(|triangle| area(&triangulation.points, *triangle) > 0.0));
+    }
+
+    #[test]
+    fn project_collinear_points() {
+        let points = [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(2.0, 0.0, 0.0),
+        ];
+
+        assert!(PlaneProjection::from_points(&points).is_none());
+    }
+}
@@ -0,0 +1,172 @@
+//! Spatial welding of vertices.
+
+use decorum::Real;
+use num::{One, ToPrimitive, Zero};
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::{AsPosition, Position};
+
+type Cell = SmallVec<[i64; 4]>;
+
+/// Welds vertices with positions that are within a distance of each other.
+///
+/// Vertices are combined into clusters using the given function, which
+/// receives the vertices of a cluster in their original order. Returns the
+/// index of the welded vertex for each input vertex and the welded vertices,
+/// which are ordered by the first appearance of their clusters. Welded indices
+/// are never greater than the indices of their input vertices.
+pub fn weld_with<T, F>(
+    vertices: Vec<T>,
+    tolerance: Scalar<Position<T>>,
+    mut f: F,
+) -> (Vec<usize>, Vec<T>)
+where
+    T: AsPosition,
+    Position<T>: EuclideanSpace,
+    Vector<Position<T>>: InnerSpace,
+    Scalar<Position<T>>: ToPrimitive,
+    F: FnMut(&[T]) -> T,
+{
+    let points = vertices
+        .iter()
+        .map(|vertex| *vertex.as_position())
+        .collect::<Vec<_>>();
+    let (indices, n) = cluster(&points, tolerance);
+    let mut clusters = (0..n).map(|_| vec![]).collect::<Vec<_>>();
+    for (vertex, index) in vertices.into_iter().zip(indices.iter()) {
+        clusters[*index].push(vertex);
+    }
+    (indices, clusters.iter().map(|cluster| f(cluster)).collect())
+}
+
+// Partitions points into clusters using a grid hash. Each point joins the
+// first cluster with a leading point that is within the tolerance, so clusters
+// do not chain across points that are each within the tolerance of one
+// another. Returns the cluster of each point and the number of clusters.
+fn cluster<S>(points: &[S], tolerance: Scalar<S>) -> (Vec<usize>, usize)
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+    Scalar<S>: ToPrimitive,
+{
+    let origin = match points.first() {
+        Some(origin) => *origin,
+        _ => {
+            return (vec![], 0);
+        }
+    };
+    // Coincident points are always welded, so the tolerance is clamped to
+    // zero and the size of cells must be positive even if the tolerance is
+    // not.
+    let (tolerance, size) = if tolerance > Zero::zero() {
+        (tolerance, tolerance)
+    }
+    else {
+        (Zero::zero(), One::one())
+    };
+    // Construct an orthonormal basis that spans the offsets of the points via
+    // Gram-Schmidt, which only requires inner products and so does not depend
+    // on the dimensionality of the space. Projection onto this basis never
+    // increases distances, so points within the tolerance of each other are
+    // always found in adjacent cells.
+    let mut basis: Vec<Vector<S>> = vec![];
+    for point in points {
+        let residual = basis
+            .iter()
+            .fold(*point - origin, |x, axis| x - (*axis * axis.dot(x)));
+        if residual.magnitude() > size {
+            basis.extend(residual.normalize());
+        }
+    }
+    let locate = |point: S| {
+        let offset = point - origin;
+        basis
+            .iter()
+            .map(|axis| (offset.dot(*axis) / size).floor().to_i64().unwrap_or(0))
+            .collect::<Cell>()
+    };
+
+    let mut grid = HashMap::<Cell, Vec<usize>>::new();
+    let mut leaders = vec![];
+    let indices = points
+        .iter()
+        .map(|point| {
+            let cell = locate(*point);
+            let mut neighbors = vec![cell.clone()];
+            for axis in 0..cell.len() {
+                neighbors = neighbors
+                    .into_iter()
+                    .flat_map(|neighbor| {
+                        (-1i64..=1).map(move |offset| {
+                            let mut neighbor = neighbor.clone();
+                            neighbor[axis] = neighbor[axis].saturating_add(offset);
+                            neighbor
+                        })
+                    })
+                    .collect();
+            }
+            neighbors
+                .iter()
+                .flat_map(|neighbor| grid.get(neighbor))
+                .flatten()
+                .cloned()
+                .filter(|index| (leaders[*index] - *point).magnitude() <= tolerance)
+                .min()
+                .unwrap_or_else(|| {
+                    let index = leaders.len();
+                    leaders.push(*point);
+                    grid.entry(cell).or_default().push(index);
+                    index
+                })
+        })
+        .collect();
+    (indices, leaders.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::geometry::weld;
+
+    #[test]
+    fn weld_with_noise() {
+        let vertices = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(1e-7, -1e-7, 0.0),
+            Point3::new(1.0 - 1e-7, 1e-7, 1e-7),
+            Point3::new(0.0, 1.0, 0.0),
+        ];
+        let (indices, vertices) = weld::weld_with(vertices, 1e-5, |vertices| vertices[0]);
+
+        assert_eq!(vec![0, 1, 0, 1, 2], indices);
+        assert_eq!(3, vertices.len());
+    }
+
+    #[test]
+    fn weld_without_chaining() {
+        // Each point is within the tolerance of its neighbors, but points are
+        // only welded into the first point of a cluster.
+        let vertices = (0..4)
+            .map(|x| Point3::new(f64::from(x) * 0.75, 0.0, 0.0))
+            .collect::<Vec<_>>();
+        let (indices, _) = weld::weld_with(vertices, 1.0, |vertices| vertices[0]);
+
+        assert_eq!(vec![0, 0, 1, 1], indices);
+    }
+
+    #[test]
+    fn weld_with_negative_tolerance() {
+        let vertices = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1e-7, 0.0, 0.0),
+            Point3::new(0.0, 0.0, 0.0),
+        ];
+        let (indices, vertices) = weld::weld_with(vertices, -1.0, |vertices| vertices[0]);
+
+        assert_eq!(vec![0, 1, 0], indices);
+        assert_eq!(2, vertices.len());
+    }
+}
@@ -0,0 +1,777 @@
+//! Conway polyhedron operators.
+//!
+//! This module provides [Conway operators][conway] over entire
+//! [`MeshGraph`]s. Each operator constructs a new graph with a topology
+//! derived from the topology of the given graph. Operators can be composed to
+//! construct families of polyhedra, such as geodesic and Goldberg polyhedra.
+//!
+//! Operators have a `_with` variant that accepts functions that compute the
+//! positions of any inserted vertices. All other vertex data is copied from a
+//! related vertex in the given graph and faces that are derived from a face
+//! in the given graph copy its data. Any other faces use default data and arc
+//! and edge data are not preserved.
+//!
+//! Operators only insert faces for vertices and edges that are surrounded by
+//! faces, so open graphs are not closed by any operator. Edges that are not
+//! adjacent to any face are removed. Operators return an error if the derived
+//! topology cannot be inserted into a graph, such as when two faces share more
+//! than one edge.
+//!
+//! # Examples
+//!
+//! Constructing a truncated octahedron from a cube:
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::R64;
+//! use nalgebra::Point3;
+//! use plexus::graph::{conway, MeshGraph};
+//! use plexus::index::HashIndexer;
+//! use plexus::prelude::*;
+//! use plexus::primitive::cube::Cube;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::Tetragon;
+//!
+//! type E3 = Point3<R64>;
+//!
+//! let (indices, vertices) = Cube::new()
+//!     .polygons::<Position<E3>>()
+//!     .index_vertices::<Tetragon<usize>, _>(HashIndexer::default());
+//! let cube = MeshGraph::<E3>::from_raw_buffers(indices, vertices).unwrap();
+//!
+//! let octahedron = conway::dual(&cube).unwrap();
+//! let graph = conway::truncate(&octahedron).unwrap();
+//!
+//! assert_eq!(14, graph.face_count());
+//! assert_eq!(24, graph.vertex_count());
+//! ```
+//!
+//! [conway]: https://en.wikipedia.org/wiki/conway_polyhedron_notation
+//! [`MeshGraph`]: crate::graph::MeshGraph
+
+use std::collections::HashMap;
+use theon::space::EuclideanSpace;
+use theon::AsPositionMut;
+
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::edge::{ArcKey, ArcView, EdgeKey, EdgeView};
+use crate::graph::face::{FaceKey, FaceView};
+use crate::graph::geometry::{EdgeMidpoint, FaceCentroid, VertexPosition};
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::subdivision::{centroid, insert_face, insert_with_position};
+use crate::graph::vertex::{VertexKey, VertexView};
+use crate::graph::{GraphError, MeshGraph, OptionExt as _};
+use crate::transact::Transact;
+use crate::IteratorExt as _;
+
+type Mutation<G> = mutation::Mutation<Immediate<MeshGraph<G>>>;
+
+/// Constructs the dual of a graph, placing vertices at the centroids of faces.
+///
+/// See [`dual_with`].
+pub fn dual<G>(graph: &MeshGraph<G>) -> Result<MeshGraph<G>, GraphError>
+where
+    G: FaceCentroid + GraphData,
+    G::Vertex: AsPositionMut,
+{
+    dual_with(graph, |face| face.centroid())
+}
+
+/// Constructs the dual of a graph (`d`).
+///
+/// A face is inserted for each vertex that is surrounded by faces. The face is
+/// formed from vertices inserted for each of the faces about that vertex, so
+/// faces that are not adjacent to any such vertex have no vertex in the dual.
+/// The given function computes the position of the vertex inserted for each
+/// face.
+pub fn dual_with<G, F>(graph: &MeshGraph<G>, f: F) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    F: FnMut(FaceView<&MeshGraph<G>>) -> VertexPosition<G>,
+{
+    dual_with_boundary(
+        graph,
+        f,
+        None::<fn(EdgeView<&MeshGraph<G>>) -> VertexPosition<G>>,
+    )
+}
+
+/// Constructs the dual of a graph, optionally closing boundary vertices.
+///
+/// If a function is given for boundary edges, then a face is also inserted for
+/// each boundary vertex that is formed from the vertices of the adjacent
+/// faces, a vertex for each incident boundary edge, and a copy of the boundary
+/// vertex itself. The function computes the position of the vertex inserted
+/// for each boundary edge.
+pub(in crate::graph) fn dual_with_boundary<G, F, H>(
+    graph: &MeshGraph<G>,
+    mut f: F,
+    mut boundary: Option<H>,
+) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    F: FnMut(FaceView<&MeshGraph<G>>) -> VertexPosition<G>,
+    H: FnMut(EdgeView<&MeshGraph<G>>) -> VertexPosition<G>,
+{
+    let mut mutation = Mutation::from(MeshGraph::new());
+    let mut faces = HashMap::<FaceKey, VertexKey>::with_capacity(graph.face_count());
+    let mut edges = HashMap::<EdgeKey, VertexKey>::new();
+    for vertex in graph.vertices() {
+        if let Some(arcs) = interior_arcs(vertex) {
+            let perimeter = arcs
+                .into_iter()
+                .map(|arc| face_vertex(&mut mutation, &mut faces, arc, &mut f))
+                .collect::<Vec<_>>();
+            insert_face(&mut mutation, &perimeter, Default::default())?;
+        }
+        else if let Some(h) = boundary.as_mut() {
+            if let Some((arcs, outgoing)) = boundary_arcs(vertex) {
+                let first = arcs[0];
+                let mut perimeter = arcs
+                    .into_iter()
+                    .map(|arc| face_vertex(&mut mutation, &mut faces, arc, &mut f))
+                    .collect::<Vec<_>>();
+                for edge in [outgoing.edge(), first.edge()] {
+                    let key = *edges.entry(edge.key()).or_insert_with(|| {
+                        insert_with_position(&mut mutation, edge.arc().source_vertex(), h(edge))
+                    });
+                    perimeter.push(key);
+                }
+                // The boundary vertex is inserted between the vertices of its
+                // incident boundary edges.
+                let key = mutation::vertex::insert(&mut mutation, vertex.get().clone());
+                perimeter.insert(perimeter.len() - 1, key);
+                insert_face(&mut mutation, &perimeter, Default::default())?;
+            }
+        }
+    }
+    mutation.commit().map_err(|(_, error)| error)
+}
+
+/// Pokes each face of a graph at its centroid.
+///
+/// See [`kis_with`].
+pub fn kis<G>(graph: &MeshGraph<G>) -> Result<MeshGraph<G>, GraphError>
+where
+    G: FaceCentroid + GraphData,
+    G::Vertex: AsPositionMut,
+{
+    kis_with(graph, |face| face.centroid())
+}
+
+/// Pokes each face of a graph (`k`).
+///
+/// A vertex is inserted for each face and each face is replaced by a triangle
+/// fan about that vertex. The given function computes the position of the
+/// vertex inserted for each face.
+///
+/// This is the whole-graph analog of [`FaceView::poke_with`].
+///
+/// [`FaceView::poke_with`]: crate::graph::FaceView::poke_with
+pub fn kis_with<G, F>(graph: &MeshGraph<G>, mut f: F) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    F: FnMut(FaceView<&MeshGraph<G>>) -> VertexPosition<G>,
+{
+    let mut mutation = Mutation::from(MeshGraph::new());
+    let mut vertices = Vertices::with_capacity(graph.vertex_count());
+    for face in graph.faces() {
+        let c = insert_with_position(&mut mutation, face.arc().source_vertex(), f(face));
+        let perimeter = face
+            .adjacent_vertices()
+            .map(|vertex| vertices.get_or_insert(&mut mutation, vertex))
+            .collect::<Vec<_>>();
+        for (a, b) in perimeter.into_iter().perimeter() {
+            insert_face(&mut mutation, &[a, b, c], face.get().clone())?;
+        }
+    }
+    mutation.commit().map_err(|(_, error)| error)
+}
+
+/// Truncates the vertices of a graph, cutting each edge into thirds.
+///
+/// See [`truncate_with`].
+pub fn truncate<G>(graph: &MeshGraph<G>) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+{
+    truncate_with(graph, |arc| {
+        let (a, b) = (
+            *arc.source_vertex().position(),
+            *arc.destination_vertex().position(),
+        );
+        centroid([a, a, b])
+    })
+}
+
+/// Truncates the vertices of a graph (`t`).
+///
+/// A vertex is inserted for each arc near its source vertex. Each face is
+/// replaced by a face with twice the arity and a face is inserted for each
+/// vertex that is surrounded by faces. The given function computes the
+/// position of the vertex inserted for each arc.
+pub fn truncate_with<G, F>(graph: &MeshGraph<G>, f: F) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    F: FnMut(ArcView<&MeshGraph<G>>) -> VertexPosition<G>,
+{
+    let mut mutation = Mutation::from(MeshGraph::new());
+    let arcs = arc_vertices(&mut mutation, graph, f);
+    for face in graph.faces() {
+        let perimeter = face
+            .adjacent_arcs()
+            .flat_map(|arc| {
+                [
+                    arc_vertex(&arcs, arc),
+                    arc_vertex(&arcs, arc.opposite_arc()),
+                ]
+            })
+            .collect::<Vec<_>>();
+        insert_face(&mut mutation, &perimeter, face.get().clone())?;
+    }
+    for vertex in graph.vertices() {
+        if let Some(outgoing) = interior_arcs(vertex) {
+            let perimeter = outgoing
+                .into_iter()
+                .map(|arc| arc_vertex(&arcs, arc))
+                .collect::<Vec<_>>();
+            insert_face(&mut mutation, &perimeter, Default::default())?;
+        }
+    }
+    mutation.commit().map_err(|(_, error)| error)
+}
+
+/// Rectifies a graph, placing vertices at the midpoints of edges.
+///
+/// See [`ambo_with`].
+pub fn ambo<G>(graph: &MeshGraph<G>) -> Result<MeshGraph<G>, GraphError>
+where
+    G: EdgeMidpoint + GraphData,
+    G::Vertex: AsPositionMut,
+{
+    ambo_with(graph, |edge| edge.midpoint())
+}
+
+/// Rectifies a graph (`a`).
+///
+/// A vertex is inserted for each edge. Each face is replaced by a face formed
+/// from the vertices of its edges and a face is inserted for each vertex that
+/// is surrounded by faces. The given function computes the position of the
+/// vertex inserted for each edge.
+pub fn ambo_with<G, F>(graph: &MeshGraph<G>, mut f: F) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    F: FnMut(EdgeView<&MeshGraph<G>>) -> VertexPosition<G>,
+{
+    let mut mutation = Mutation::from(MeshGraph::new());
+    let mut edges = HashMap::<EdgeKey, VertexKey>::with_capacity(graph.edge_count());
+    for edge in graph.edges() {
+        if edge.arc().adjacent_faces().count() > 0 {
+            let key = insert_with_position(&mut mutation, edge.arc().source_vertex(), f(edge));
+            edges.insert(edge.key(), key);
+        }
+    }
+    let edge_vertex =
+        |arc: ArcView<&MeshGraph<G>>| *edges.get(&arc.edge().key()).expect_consistent();
+    for face in graph.faces() {
+        let perimeter = face.adjacent_arcs().map(edge_vertex).collect::<Vec<_>>();
+        insert_face(&mut mutation, &perimeter, face.get().clone())?;
+    }
+    for vertex in graph.vertices() {
+        if let Some(outgoing) = interior_arcs(vertex) {
+            let perimeter = outgoing.into_iter().map(edge_vertex).collect::<Vec<_>>();
+            insert_face(&mut mutation, &perimeter, Default::default())?;
+        }
+    }
+    mutation.commit().map_err(|(_, error)| error)
+}
+
+/// Expands a graph, moving the corners of each face halfway toward its
+/// centroid.
+///
+/// See [`expand_with`].
+pub fn expand<G>(graph: &MeshGraph<G>) -> Result<MeshGraph<G>, GraphError>
+where
+    G: FaceCentroid + GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+{
+    expand_with(graph, corner_toward_centroid)
+}
+
+/// Expands a graph (`e`), also known as cantellation.
+///
+/// A vertex is inserted for each corner of each face. Each face is replaced
+/// by a face formed from the vertices of its corners and faces are inserted
+/// for each edge and each vertex that are surrounded by faces. The faces
+/// inserted for edges are quadrilaterals. The given function computes the
+/// position of the vertex inserted for each corner, which is identified by
+/// the arc in the face's perimeter that leaves the corner's vertex.
+pub fn expand_with<G, F>(graph: &MeshGraph<G>, f: F) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    F: FnMut(ArcView<&MeshGraph<G>>) -> VertexPosition<G>,
+{
+    corner_cut_with(graph, f, |mutation, [a, b, c, d]| {
+        insert_face(mutation, &[a, b, c, d], Default::default()).map(|_| ())
+    })
+}
+
+/// Snubs a graph, moving the corners of each face halfway toward its
+/// centroid.
+///
+/// See [`snub_with`].
+pub fn snub<G>(graph: &MeshGraph<G>) -> Result<MeshGraph<G>, GraphError>
+where
+    G: FaceCentroid + GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+{
+    snub_with(graph, corner_toward_centroid)
+}
+
+/// Snubs a graph (`s`).
+///
+/// This operator is similar to [`expand_with`], but inserts a pair of
+/// triangles rather than a quadrilateral for each edge. The triangles are
+/// split along a consistent diagonal, which gives the graph a chirality. The
+/// positions of vertices are computed in the same way as [`expand_with`], so
+/// the given function should typically rotate corners about the centroid of
+/// their face to produce a conventional snub polyhedron.
+pub fn snub_with<G, F>(graph: &MeshGraph<G>, f: F) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    F: FnMut(ArcView<&MeshGraph<G>>) -> VertexPosition<G>,
+{
+    corner_cut_with(graph, f, |mutation, [a, b, c, d]| {
+        insert_face(mutation, &[a, b, c], Default::default())?;
+        insert_face(mutation, &[a, c, d], Default::default()).map(|_| ())
+    })
+}
+
+/// Gyrates a graph, placing vertices at the centroids of faces and cutting
+/// each edge into thirds.
+///
+/// See [`gyro_with`].
+pub fn gyro<G>(graph: &MeshGraph<G>) -> Result<MeshGraph<G>, GraphError>
+where
+    G: FaceCentroid + GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+{
+    gyro_with(
+        graph,
+        |face| face.centroid(),
+        |arc| {
+            let (a, b) = (
+                *arc.source_vertex().position(),
+                *arc.destination_vertex().position(),
+            );
+            centroid([a, a, b])
+        },
+    )
+}
+
+/// Gyrates a graph (`g`).
+///
+/// A vertex is inserted for each face and for each arc near its source vertex.
+/// Each face with arity $n$ is replaced by $n$ pentagons that share the vertex
+/// inserted for the face. The first given function computes the position of
+/// the vertex inserted for each face and the second computes the position of
+/// the vertex inserted for each arc.
+///
+/// Gyro is the dual of snub. Like [`kis`] and [`truncate`], gyro can be
+/// composed with [`dual`] to construct Goldberg polyhedra and their duals, the
+/// geodesic polyhedra.
+pub fn gyro_with<G, F, H>(graph: &MeshGraph<G>, mut f: F, g: H) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    F: FnMut(FaceView<&MeshGraph<G>>) -> VertexPosition<G>,
+    H: FnMut(ArcView<&MeshGraph<G>>) -> VertexPosition<G>,
+{
+    let mut mutation = Mutation::from(MeshGraph::new());
+    let arcs = arc_vertices(&mut mutation, graph, g);
+    let mut vertices = Vertices::with_capacity(graph.vertex_count());
+    for face in graph.faces() {
+        let c = insert_with_position(&mut mutation, face.arc().source_vertex(), f(face));
+        for arc in face.adjacent_arcs() {
+            let previous = arc.previous_arc();
+            let perimeter = [
+                c,
+                arc_vertex(&arcs, previous.opposite_arc()),
+                vertices.get_or_insert(&mut mutation, arc.source_vertex()),
+                arc_vertex(&arcs, arc),
+                arc_vertex(&arcs, arc.opposite_arc()),
+            ];
+            insert_face(&mut mutation, &perimeter, face.get().clone())?;
+        }
+    }
+    mutation.commit().map_err(|(_, error)| error)
+}
+
+/// Vertices copied from a graph into a mutation.
+struct Vertices {
+    keys: HashMap<VertexKey, VertexKey>,
+}
+
+impl Vertices {
+    fn with_capacity(capacity: usize) -> Self {
+        Vertices {
+            keys: HashMap::with_capacity(capacity),
+        }
+    }
+
+    fn get_or_insert<G>(
+        &mut self,
+        mutation: &mut Mutation<G>,
+        vertex: VertexView<&MeshGraph<G>>,
+    ) -> VertexKey
+    where
+        G: GraphData,
+    {
+        *self
+            .keys
+            .entry(vertex.key())
+            .or_insert_with(|| mutation::vertex::insert(mutation, vertex.get().clone()))
+    }
+}
+
+/// Inserts a vertex for each corner of each face and faces for each face,
+/// interior edge, and interior vertex. Faces for edges are inserted by the
+/// given function from a quadrilateral.
+fn corner_cut_with<G, F, E>(
+    graph: &MeshGraph<G>,
+    mut f: F,
+    mut g: E,
+) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    F: FnMut(ArcView<&MeshGraph<G>>) -> VertexPosition<G>,
+    E: FnMut(&mut Mutation<G>, [VertexKey; 4]) -> Result<(), GraphError>,
+{
+    let mut mutation = Mutation::from(MeshGraph::new());
+    // Corners are identified by the arc that leaves the corner's vertex.
+    let mut corners = HashMap::<ArcKey, VertexKey>::with_capacity(graph.arc_count());
+    for face in graph.faces() {
+        for arc in face.adjacent_arcs() {
+            let key = insert_with_position(&mut mutation, arc.source_vertex(), f(arc));
+            corners.insert(arc.key(), key);
+        }
+    }
+    let corner = |arc: ArcView<&MeshGraph<G>>| *corners.get(&arc.key()).expect_consistent();
+    for face in graph.faces() {
+        let perimeter = face.adjacent_arcs().map(corner).collect::<Vec<_>>();
+        insert_face(&mut mutation, &perimeter, face.get().clone())?;
+    }
+    for arc in graph
+        .edges()
+        .filter(|edge| !edge.is_boundary_edge())
+        .map(|edge| edge.into_arc())
+    {
+        // Given the arc $\overrightarrow{AB}$, the quadrilateral is formed
+        // from the corners at $B$ and $A$ in the face of the arc followed by
+        // the corners at $A$ and $B$ in the face of the opposite arc.
+        let opposite = arc.opposite_arc();
+        let quadrilateral = [
+            corner(arc.next_arc()),
+            corner(arc),
+            corner(opposite.next_arc()),
+            corner(opposite),
+        ];
+        g(&mut mutation, quadrilateral)?;
+    }
+    for vertex in graph.vertices() {
+        if let Some(outgoing) = interior_arcs(vertex) {
+            let perimeter = outgoing.into_iter().map(corner).collect::<Vec<_>>();
+            insert_face(&mut mutation, &perimeter, Default::default())?;
+        }
+    }
+    mutation.commit().map_err(|(_, error)| error)
+}
+
+fn corner_toward_centroid<G>(arc: ArcView<&MeshGraph<G>>) -> VertexPosition<G>
+where
+    G: FaceCentroid + GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+{
+    let p = *arc.source_vertex().position();
+    centroid([p, arc.face().expect_consistent().centroid()])
+}
+
+/// Inserts a vertex for each arc of each edge that is adjacent to a face.
+fn arc_vertices<G, F>(
+    mutation: &mut Mutation<G>,
+    graph: &MeshGraph<G>,
+    mut f: F,
+) -> HashMap<ArcKey, VertexKey>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    F: FnMut(ArcView<&MeshGraph<G>>) -> VertexPosition<G>,
+{
+    let mut arcs = HashMap::with_capacity(graph.arc_count());
+    for edge in graph.edges() {
+        let arc = edge.arc();
+        if arc.adjacent_faces().count() > 0 {
+            for arc in [arc, arc.opposite_arc()] {
+                let key = insert_with_position(mutation, arc.source_vertex(), f(arc));
+                arcs.insert(arc.key(), key);
+            }
+        }
+    }
+    arcs
+}
+
+fn arc_vertex<G>(arcs: &HashMap<ArcKey, VertexKey>, arc: ArcView<&MeshGraph<G>>) -> VertexKey
+where
+    G: GraphData,
+{
+    *arcs.get(&arc.key()).expect_consistent()
+}
+
+/// Gets the vertex inserted for the face of an arc.
+///
+/// The vertex is inserted when it is first used, so that faces that are not
+/// adjacent to any face in the derived graph do not insert isolated vertices.
+fn face_vertex<G, F>(
+    mutation: &mut Mutation<G>,
+    faces: &mut HashMap<FaceKey, VertexKey>,
+    arc: ArcView<&MeshGraph<G>>,
+    f: &mut F,
+) -> VertexKey
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    F: FnMut(FaceView<&MeshGraph<G>>) -> VertexPosition<G>,
+{
+    let face = arc.into_face().expect_consistent();
+    *faces
+        .entry(face.key())
+        .or_insert_with(|| insert_with_position(mutation, face.arc().source_vertex(), f(face)))
+}
+
+/// Gets the outgoing arcs of a vertex that is surrounded by faces.
+///
+/// The arcs are ordered such that the faces of the arcs are visited in the
+/// order opposite to the perimeters of those faces. Inserting a face from
+/// vertices derived from these arcs in this order yields a face that is
+/// consistently oriented with faces derived from the adjacent faces.
+fn interior_arcs<G>(vertex: VertexView<&MeshGraph<G>>) -> Option<Vec<ArcView<&MeshGraph<G>>>>
+where
+    G: GraphData,
+{
+    if vertex.valence() < 3
+        || vertex
+            .outgoing_arcs()
+            .any(|arc| arc.edge().is_boundary_edge())
+    {
+        None
+    }
+    else {
+        Some(rotation(vertex))
+    }
+}
+
+/// Gets the outgoing arcs of a vertex along a single boundary.
+///
+/// The arcs are ordered in the same way as [`interior_arcs`], beginning with
+/// the arc that leaves the vertex along the boundary. The outgoing boundary arc
+/// (which has no face) is excluded from the arcs and returned separately.
+fn boundary_arcs<G>(
+    vertex: VertexView<&MeshGraph<G>>,
+) -> Option<(Vec<ArcView<&MeshGraph<G>>>, ArcView<&MeshGraph<G>>)>
+where
+    G: GraphData,
+{
+    if vertex.adjacent_faces().count() == 0 {
+        return None;
+    }
+    let mut arcs = rotation(vertex);
+    let mut boundaries = arcs
+        .iter()
+        .enumerate()
+        .filter(|(_, arc)| arc.face().is_none())
+        .map(|(index, _)| index);
+    match (boundaries.next(), boundaries.next()) {
+        (Some(index), None) => {
+            arcs.rotate_left(index + 1);
+            let outgoing = arcs.pop().expect_consistent();
+            Some((arcs, outgoing))
+        }
+        // Vertices that are not on a boundary or that are shared by more than
+        // one boundary are not supported.
+        _ => None,
+    }
+}
+
+/// Gets the outgoing arcs of a vertex in the order opposite to the perimeters
+/// of their faces.
+fn rotation<G>(vertex: VertexView<&MeshGraph<G>>) -> Vec<ArcView<&MeshGraph<G>>>
+where
+    G: GraphData,
+{
+    let valence = vertex.valence();
+    let mut arc = vertex.into_outgoing_arc();
+    let start = arc.key();
+    let mut arcs = Vec::with_capacity(valence);
+    loop {
+        arcs.push(arc);
+        arc = arc.into_previous_arc().into_opposite_arc();
+        if arc.key() == start || arcs.len() >= valence {
+            break;
+        }
+    }
+    arcs
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::tests::grid;
+    use crate::graph::{conway, MeshGraph};
+    use crate::index::HashIndexer;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::Tetragon;
+
+    type E3 = Point3<R64>;
+
+    fn cube() -> MeshGraph<E3> {
+        let (indices, vertices) = Cube::new()
+            .polygons::<Position<E3>>()
+            .index_vertices::<Tetragon<usize>, _>(HashIndexer::default());
+        MeshGraph::from_raw_buffers(indices, vertices).unwrap()
+    }
+
+    fn is_closed(graph: &MeshGraph<E3>) -> bool {
+        graph.edges().all(|edge| !edge.is_boundary_edge())
+    }
+
+    fn arities(graph: &MeshGraph<E3>) -> Vec<usize> {
+        let mut arities = graph.faces().map(|face| face.arity()).collect::<Vec<_>>();
+        arities.sort_unstable();
+        arities
+    }
+
+    #[test]
+    fn dual_cube() {
+        let graph = conway::dual(&cube()).unwrap();
+
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(vec![3; 8], arities(&graph));
+        assert!(is_closed(&graph));
+        // The dual of the dual is topologically equivalent to the original.
+        let graph = conway::dual(&graph).unwrap();
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(vec![4; 6], arities(&graph));
+    }
+
+    #[test]
+    fn dual_open_grid() {
+        // The triangulated grid has one interior vertex.
+        let graph = conway::dual(&grid(3)).unwrap();
+
+        // Triangles that are not adjacent to the interior vertex have no
+        // vertex in the dual.
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+        assert_eq!(6, graph.faces().next().unwrap().arity());
+    }
+
+    #[test]
+    fn kis_cube() {
+        let graph = conway::kis(&cube()).unwrap();
+
+        assert_eq!(14, graph.vertex_count());
+        assert_eq!(36, graph.edge_count());
+        assert_eq!(vec![3; 24], arities(&graph));
+        assert!(is_closed(&graph));
+    }
+
+    #[test]
+    fn truncate_cube() {
+        let graph = conway::truncate(&cube()).unwrap();
+
+        assert_eq!(24, graph.vertex_count());
+        assert_eq!(36, graph.edge_count());
+        assert_eq!([vec![3; 8], vec![8; 6]].concat(), arities(&graph));
+        assert!(is_closed(&graph));
+    }
+
+    #[test]
+    fn ambo_cube() {
+        let graph = conway::ambo(&cube()).unwrap();
+
+        assert_eq!(12, graph.vertex_count());
+        assert_eq!(24, graph.edge_count());
+        assert_eq!([vec![3; 8], vec![4; 6]].concat(), arities(&graph));
+        assert!(is_closed(&graph));
+    }
+
+    #[test]
+    fn expand_cube() {
+        let graph = conway::expand(&cube()).unwrap();
+
+        assert_eq!(24, graph.vertex_count());
+        assert_eq!(48, graph.edge_count());
+        assert_eq!([vec![3; 8], vec![4; 18]].concat(), arities(&graph));
+        assert!(is_closed(&graph));
+    }
+
+    #[test]
+    fn snub_cube() {
+        let graph = conway::snub(&cube()).unwrap();
+
+        assert_eq!(24, graph.vertex_count());
+        assert_eq!(60, graph.edge_count());
+        assert_eq!([vec![3; 32], vec![4; 6]].concat(), arities(&graph));
+        assert!(is_closed(&graph));
+    }
+
+    #[test]
+    fn gyro_cube() {
+        let graph = conway::gyro(&cube()).unwrap();
+
+        assert_eq!(38, graph.vertex_count());
+        assert_eq!(60, graph.edge_count());
+        assert_eq!(vec![5; 24], arities(&graph));
+        assert!(is_closed(&graph));
+        // Gyro is the dual of snub.
+        let graph = conway::dual(&graph).unwrap();
+        assert_eq!([vec![3; 32], vec![4; 6]].concat(), arities(&graph));
+    }
+
+    #[test]
+    fn ambo_open() {
+        let graph = MeshGraph::<E3>::from(Tetragon::from([
+            (1.0, 1.0, 0.0),
+            (-1.0, 1.0, 0.0),
+            (-1.0, -1.0, 0.0),
+            (1.0, -1.0, 0.0),
+        ]));
+        let graph = conway::ambo(&graph).unwrap();
+
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+    }
+}
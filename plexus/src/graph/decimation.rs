@@ -0,0 +1,449 @@
+//! Mesh decimation via quadric error metrics.
+
+use decorum::Real;
+use num::{NumCast, One, Zero};
+use smallvec::SmallVec;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::ops::Add;
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use theon::{AsPosition, AsPositionMut};
+use typenum::U3;
+
+use crate::graph::data::GraphData;
+use crate::graph::edge::ArcKey;
+use crate::graph::geometry::{self, FaceNormal, VertexPosition};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{DecimationBoundary, DecimationTarget, MeshGraph, OptionExt as _};
+use crate::IteratorExt as _;
+
+type Triplet<T> = (T, T, T);
+
+// Symmetric quadric $Q = (A, b, c)$ that measures the error
+// $x^TAx + 2b^Tx + c$ of a point $x$. The upper triangle of $A$ is stored in
+// row-major order.
+#[derive(Clone, Copy)]
+struct Quadric<T> {
+    a: [T; 6],
+    b: [T; 3],
+    c: T,
+}
+
+impl<T> Quadric<T>
+where
+    T: NumCast + Real,
+{
+    fn zero() -> Self {
+        Quadric {
+            a: [Zero::zero(); 6],
+            b: [Zero::zero(); 3],
+            c: Zero::zero(),
+        }
+    }
+
+    // Gets the quadric of the squared distance to the plane with the given
+    // unit normal that passes through the given point.
+    fn from_plane((x, y, z): Triplet<T>, (px, py, pz): Triplet<T>) -> Self {
+        let d = -((x * px) + (y * py) + (z * pz));
+        Quadric {
+            a: [x * x, x * y, x * z, y * y, y * z, z * z],
+            b: [x * d, y * d, z * d],
+            c: d * d,
+        }
+    }
+
+    fn error(&self, (x, y, z): Triplet<T>) -> T {
+        let [xx, xy, xz, yy, yz, zz] = self.a;
+        let [bx, by, bz] = self.b;
+        let two = T::one() + T::one();
+        (x * x * xx)
+            + (y * y * yy)
+            + (z * z * zz)
+            + (two * ((x * y * xy) + (x * z * xz) + (y * z * yz)))
+            + (two * ((x * bx) + (y * by) + (z * bz)))
+            + self.c
+    }
+
+    // Gets the point that minimizes the error of the quadric by solving
+    // $Ax = -b$. Returns `None` if $A$ is (nearly) singular, such as when the
+    // quadric only includes coplanar or collinear planes.
+    fn minimum(&self) -> Option<Triplet<T>> {
+        let [xx, xy, xz, yy, yz, zz] = self.a;
+        let [bx, by, bz] = self.b;
+        geometry::solve([[xx, xy, xz], [xy, yy, yz], [xz, yz, zz]], [-bx, -by, -bz])
+            .map(|[x, y, z]| (x, y, z))
+    }
+}
+
+impl<T> Add for Quadric<T>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let mut output = self;
+        for (a, b) in output.a.iter_mut().zip(other.a.iter()) {
+            *a = *a + *b;
+        }
+        for (a, b) in output.b.iter_mut().zip(other.b.iter()) {
+            *a = *a + *b;
+        }
+        output.c = output.c + other.c;
+        output
+    }
+}
+
+// Collapse of an arc that places its destination vertex at a position with a
+// quadric error. Candidates are stamped with the versions of the vertices of
+// the arc and become stale when either vertex is modified.
+struct Candidate<S>
+where
+    S: EuclideanSpace,
+{
+    cost: Scalar<S>,
+    ab: ArcKey,
+    position: S,
+    stamps: (usize, usize),
+}
+
+impl<S> Eq for Candidate<S> where S: EuclideanSpace {}
+
+impl<S> Ord for Candidate<S>
+where
+    S: EuclideanSpace,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reverse the ordering such that heaps yield the least cost first.
+        other
+            .cost
+            .partial_cmp(&self.cost)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<S> PartialEq for Candidate<S>
+where
+    S: EuclideanSpace,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S> PartialOrd for Candidate<S>
+where
+    S: EuclideanSpace,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Decimates a graph by collapsing edges in order of their quadric error.
+///
+/// Returns the number of faces that have been removed.
+pub fn decimate_with<G, F>(
+    graph: &mut MeshGraph<G>,
+    target: DecimationTarget<Scalar<VertexPosition<G>>>,
+    boundary: DecimationBoundary,
+    mut f: F,
+) -> usize
+where
+    G: FaceNormal,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+    Scalar<VertexPosition<G>>: NumCast,
+    F: FnMut(&G::Vertex, &G::Vertex, Scalar<VertexPosition<G>>) -> G::Vertex,
+{
+    let n = graph.face_count();
+    let mut quadrics = graph
+        .vertices()
+        .map(|vertex| (vertex.key(), Quadric::zero()))
+        .collect::<HashMap<_, _>>();
+    for face in graph.faces() {
+        let normal = match face.normal() {
+            Ok(normal) => normal,
+            _ => continue,
+        };
+        let plane = Quadric::from_plane(
+            normal.into_xyz(),
+            (*face.arc().source_vertex().position()).into_xyz(),
+        );
+        for vertex in face.adjacent_vertices() {
+            let quadric = quadrics.get_mut(&vertex.key()).expect_consistent();
+            *quadric = *quadric + plane;
+        }
+        // Boundary edges contribute planes that are perpendicular to the face,
+        // which penalizes collapses that move vertices away from boundaries.
+        for arc in face
+            .adjacent_arcs()
+            .filter(|arc| arc.opposite_arc().is_boundary_arc())
+        {
+            let (a, b) = (arc.source_vertex(), arc.destination_vertex());
+            let edge = *b.position() - *a.position();
+            if let Some(perpendicular) = edge.cross(normal).normalize() {
+                let plane =
+                    Quadric::from_plane(perpendicular.into_xyz(), (*a.position()).into_xyz());
+                for key in [a.key(), b.key()] {
+                    let quadric = quadrics.get_mut(&key).expect_consistent();
+                    *quadric = *quadric + plane;
+                }
+            }
+        }
+    }
+    let mut stamps = graph
+        .vertices()
+        .map(|vertex| (vertex.key(), 0usize))
+        .collect::<HashMap<_, _>>();
+    let mut heap = BinaryHeap::new();
+    for edge in graph.edges() {
+        let ab = edge.arc().key();
+        heap.extend(candidate(graph, &quadrics, &stamps, boundary, ab));
+    }
+    while let Some(Candidate {
+        cost,
+        ab,
+        position,
+        stamps: (sa, sb),
+    }) = heap.pop()
+    {
+        match target {
+            DecimationTarget::FaceCount(count) if graph.face_count() <= count => break,
+            DecimationTarget::Error(bound) if cost > bound => break,
+            _ => {}
+        }
+        let (a, b): (VertexKey, VertexKey) = ab.into();
+        if stamps.get(&a) != Some(&sa) || stamps.get(&b) != Some(&sb) {
+            continue;
+        }
+        if is_folding(graph, ab, position) {
+            continue;
+        }
+        // Get the parameter of the position projected onto the arc, which is
+        // used to interpolate vertex data.
+        let t = {
+            let arc = graph.arc(ab).expect_consistent();
+            let origin = *arc.source_vertex().position();
+            let ab = *arc.destination_vertex().position() - origin;
+            let square = ab.dot(ab);
+            if square > Zero::zero() {
+                let t = (position - origin).dot(ab) / square;
+                if t < Zero::zero() {
+                    Zero::zero()
+                }
+                else if t > One::one() {
+                    One::one()
+                }
+                else {
+                    t
+                }
+            }
+            else {
+                Zero::zero()
+            }
+        };
+        let arc = graph.arc_mut(ab).expect_consistent();
+        let collapse = arc.collapse_with(|source, destination| {
+            let mut data = f(source, destination, t);
+            *data.as_position_mut() = position;
+            data
+        });
+        if collapse.is_err() {
+            continue;
+        }
+        let quadric = quadrics.remove(&a).expect_consistent() + quadrics[&b];
+        quadrics.insert(b, quadric);
+        stamps.remove(&a);
+        *stamps.get_mut(&b).expect_consistent() += 1;
+        let arcs = graph
+            .vertex(b)
+            .expect_consistent()
+            .outgoing_arcs()
+            .keys()
+            .collect::<Vec<_>>();
+        for arc in arcs {
+            heap.extend(candidate(graph, &quadrics, &stamps, boundary, arc));
+        }
+    }
+    n - graph.face_count()
+}
+
+// Gets the candidate collapse of the edge of an arc. The arc is reoriented
+// such that vertices along boundaries are not moved if boundaries are
+// preserved. Otherwise, the destination vertex is placed at the position that
+// minimizes the combined quadric error of the vertices.
+fn candidate<G>(
+    graph: &MeshGraph<G>,
+    quadrics: &HashMap<VertexKey, Quadric<Scalar<VertexPosition<G>>>>,
+    stamps: &HashMap<VertexKey, usize>,
+    boundary: DecimationBoundary,
+    ab: ArcKey,
+) -> Option<Candidate<VertexPosition<G>>>
+where
+    G: FaceNormal,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<VertexPosition<G>>: InnerSpace,
+    Scalar<VertexPosition<G>>: NumCast,
+{
+    let arc = graph.arc(ab)?;
+    let (ab, is_fixed) = match boundary {
+        DecimationBoundary::Collapse => (ab, false),
+        DecimationBoundary::Preserve => match (
+            arc.source_vertex().is_boundary_vertex(),
+            arc.destination_vertex().is_boundary_vertex(),
+        ) {
+            (false, false) => (ab, false),
+            (false, true) => (ab, true),
+            (true, false) => (arc.opposite_arc().key(), true),
+            (true, true) => {
+                return None;
+            }
+        },
+    };
+    let arc = graph.arc(ab)?;
+    let (a, b) = (arc.source_vertex(), arc.destination_vertex());
+    let quadric = *quadrics.get(&a.key())? + *quadrics.get(&b.key())?;
+    let error = |position: VertexPosition<G>| quadric.error(position.into_xyz());
+    let position = if is_fixed {
+        *b.position()
+    }
+    else {
+        quadric
+            .minimum()
+            .map(|(x, y, z)| EuclideanSpace::from_xyz(x, y, z))
+            .unwrap_or_else(|| {
+                // Fall back to the midpoint or an endpoint of the edge.
+                let (a, b) = (*a.position(), *b.position());
+                let half = <Scalar<VertexPosition<G>> as NumCast>::from(0.5).unwrap();
+                let midpoint = a + ((b - a) * half);
+                [a, b].into_iter().fold(midpoint, |position, endpoint| {
+                    if error(endpoint) < error(position) {
+                        endpoint
+                    }
+                    else {
+                        position
+                    }
+                })
+            })
+    };
+    let cost = error(position);
+    Some(Candidate {
+        cost: if cost < Zero::zero() {
+            Zero::zero()
+        }
+        else {
+            cost
+        },
+        ab,
+        position,
+        stamps: (*stamps.get(&a.key())?, *stamps.get(&b.key())?),
+    })
+}
+
+// Determines if collapsing an arc and placing its destination vertex at the
+// given position flips the normal of any face that remains after the collapse.
+// Faces adjacent to both vertices of the arc are not examined. The normals of
+// moved faces are computed from their positions with the position substituted
+// for the vertices of the arc, so the graph is not modified.
+pub(in crate::graph) fn is_folding<G>(
+    graph: &MeshGraph<G>,
+    ab: ArcKey,
+    position: VertexPosition<G>,
+) -> bool
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+{
+    let (a, b): (VertexKey, VertexKey) = ab.into();
+    [a, b]
+        .iter()
+        .flat_map(|key| graph.vertex(*key).expect_consistent().into_adjacent_faces())
+        .filter(|face| {
+            let mut keys = face.adjacent_vertices().keys();
+            !(keys.clone().any(|key| key == a) && keys.any(|key| key == b))
+        })
+        .any(|face| {
+            let (positions, moved): (SmallVec<[_; 4]>, SmallVec<[_; 4]>) = face
+                .adjacent_vertices()
+                .map(|vertex| {
+                    let original = *vertex.position();
+                    if vertex.key() == a || vertex.key() == b {
+                        (original, position)
+                    }
+                    else {
+                        (original, original)
+                    }
+                })
+                .unzip();
+            match (geometry::normal(&positions), geometry::normal(&moved)) {
+                (Ok(normal), Ok(moved)) => normal.dot(moved) < Zero::zero(),
+                (Ok(_), Err(_)) => true,
+                // Faces with degenerate geometry have no normal to flip.
+                (Err(_), _) => false,
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::tests::grid;
+    use crate::graph::{DecimationBoundary, DecimationTarget, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn decimate_sphere() {
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 16)
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .collect();
+        let n = graph.face_count();
+        let removed = graph.decimate(
+            DecimationTarget::FaceCount(100),
+            DecimationBoundary::Preserve,
+        );
+
+        assert_eq!(n - graph.face_count(), removed);
+        assert!(graph.face_count() <= 100);
+        assert!(graph.validate().is_empty());
+        // Collapses that flip normals are rejected, so faces remain oriented
+        // away from the center of the sphere.
+        for face in graph.faces() {
+            assert!(face.normal().unwrap().dot(&face.centroid().coords) > 0.0);
+        }
+    }
+
+    #[test]
+    fn decimate_plane_with_preserved_boundary() {
+        let mut graph = grid(5);
+        let boundary = |graph: &MeshGraph<Point3<f64>>| {
+            let mut positions = graph
+                .boundaries()
+                .nth(0)
+                .unwrap()
+                .vertices()
+                .map(|vertex| (vertex.position().x, vertex.position().y))
+                .collect::<Vec<_>>();
+            positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            positions
+        };
+        let expected = boundary(&graph);
+
+        // The grid is flat, so interior vertices can be removed without error.
+        assert!(graph.decimate(DecimationTarget::Error(1e-9), DecimationBoundary::Preserve) > 0);
+        assert!(graph.validate().is_empty());
+        assert_eq!(expected, boundary(&graph));
+    }
+}
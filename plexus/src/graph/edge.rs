@@ -14,7 +14,8 @@ use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::face::{Face, FaceKey, FaceOrphan, FaceView, Ring};
 use crate::graph::geometry::{ArcNormal, EdgeMidpoint, VertexPosition};
 use crate::graph::mutation::edge::{
-    self, ArcBridgeCache, ArcExtrudeCache, EdgeRemoveCache, EdgeSplitCache,
+    self, ArcBridgeCache, ArcCollapseCache, ArcExtrudeCache, ArcFlipCache, EdgeRemoveCache,
+    EdgeSplitCache,
 };
 use crate::graph::mutation::{self, Consistent, Immediate, Mutable};
 use crate::graph::path::Path;
@@ -754,6 +755,132 @@ where
         })
     }
 
+    /// Collapses the composite edge of the arc by merging its source vertex
+    /// into its destination vertex.
+    ///
+    /// Collapsing an arc $\overrightarrow{AB}$ removes the vertex $A$ and
+    /// connects the arcs and faces of $A$ to $B$. The data of $B$ is replaced
+    /// with data provided by the given function, which accepts the data of $A$
+    /// and $B$ (in that order). Triangles adjacent to the edge degenerate and
+    /// are removed and the arity of any other adjacent faces is reduced by
+    /// one.
+    ///
+    /// Returns the destination vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collapse would create non-manifold topology.
+    /// This occurs if $A$ and $B$ do not satisfy the link condition (they
+    /// share neighbors other than the apexes of adjacent triangles or the
+    /// apexes are joined by an edge that forms triangles with both $A$ and
+    /// $B$, such as in a tetrahedron), if the edge is an interior edge that
+    /// connects two boundary vertices, or if a merged edge would not be
+    /// adjacent to any face.
+    ///
+    /// # Examples
+    ///
+    /// Collapsing a boundary edge of a quadrilateral formed from two triangles:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let key = graph
+    ///     .arcs()
+    ///     .find(|arc| arc.is_boundary_arc())
+    ///     .unwrap()
+    ///     .key();
+    /// graph
+    ///     .arc_mut(key)
+    ///     .unwrap()
+    ///     .collapse_with(|_, destination| *destination)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(3, graph.vertex_count());
+    /// assert_eq!(1, graph.face_count());
+    /// ```
+    pub fn collapse_with<F>(self, f: F) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        F: FnOnce(&G::Vertex, &G::Vertex) -> G::Vertex,
+    {
+        let cache = ArcCollapseCache::from_arc(self.to_ref())?;
+        let (storage, _) = self.unbind();
+        Ok(Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| edge::collapse_with(mutation, cache, f))
+            .map(|(storage, b)| Bind::bind(storage, b).expect_consistent())
+            .map_err(|(_, error)| error)
+            .expect_consistent())
+    }
+
+    /// Flips the composite edge of the arc, replacing it with the opposing
+    /// diagonal of the quadrilateral formed by its adjacent triangles.
+    ///
+    /// Flipping an arc $\overrightarrow{AB}$ with adjacent triangles
+    /// $\overrightarrow{\\{A,B,C\\}}$ and $\overrightarrow{\\{B,A,D\\}}$
+    /// removes the edge and inserts the triangles
+    /// $\overrightarrow{\\{C,A,D\\}}$ and $\overrightarrow{\\{D,B,C\\}}$,
+    /// which take the data of the faces of $\overrightarrow{AB}$ and
+    /// $\overrightarrow{BA}$, respectively. The arcs and edge of the inserted
+    /// diagonal take the data of the removed arcs and edge.
+    ///
+    /// Returns the arc $\overrightarrow{DC}$.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the edge is a boundary edge, if either adjacent
+    /// face is not a triangle, or if $C$ and $D$ are already connected by an
+    /// edge.
+    ///
+    /// # Examples
+    ///
+    /// Flipping the diagonal of a quadrilateral formed from two triangles:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let key = graph
+    ///     .edges()
+    ///     .find(|edge| !edge.is_boundary_edge())
+    ///     .unwrap()
+    ///     .arc()
+    ///     .key();
+    /// let arc = graph.arc_mut(key).unwrap().flip().unwrap().into_ref();
+    ///
+    /// // The flipped diagonal connects the other corners of the quadrilateral.
+    /// let position = *arc.source_vertex().get();
+    /// assert_ne!(position.x, position.y);
+    /// ```
+    pub fn flip(self) -> Result<Self, GraphError> {
+        let cache = ArcFlipCache::from_arc(self.to_ref())?;
+        let (storage, _) = self.unbind();
+        Ok(Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| edge::flip(mutation, cache))
+            .map(|(storage, arc)| Bind::bind(storage, arc).expect_consistent())
+            .map_err(|(_, error)| error)
+            .expect_consistent())
+    }
+
     // TODO: What if an edge in the bridging quadrilateral is collapsed, such as
     //       bridging arcs within a triangular ring? Document these edge cases
     //       (no pun intended).
@@ -1877,7 +2004,7 @@ mod tests {
     use nalgebra::{Point2, Point3};
 
     use crate::geometry::FromGeometry;
-    use crate::graph::{ArcKey, GraphData, MeshGraph};
+    use crate::graph::{ArcKey, GraphData, GraphError, MeshGraph};
     use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
@@ -1974,6 +2101,155 @@ mod tests {
         );
     }
 
+    #[test]
+    fn collapse_edge() {
+        // Construct a graph from four triangles about an interior vertex.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 4, 1, 2, 4, 2, 3, 4, 3, 0, 4],
+            vec![
+                (0.0, 0.0), // 0
+                (2.0, 0.0), // 1
+                (2.0, 2.0), // 2
+                (0.0, 2.0), // 3
+                (1.0, 1.0), // 4
+            ],
+            3,
+        )
+        .unwrap();
+
+        // Collapse the interior vertex into a corner.
+        let ab = find_arc(&graph, ((1.0, 1.0), (0.0, 0.0))).unwrap();
+        let vertex = graph
+            .arc_mut(ab)
+            .unwrap()
+            .collapse_with(|_, destination| *destination)
+            .unwrap()
+            .into_ref();
+
+        assert_eq!(E2::from_geometry((0.0, 0.0)), *vertex.get());
+        assert_eq!(3, vertex.valence());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(5, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+    }
+
+    #[test]
+    fn collapse_edge_with_link_conflict() {
+        // Construct a graph from three triangles about an interior vertex.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 3, 1, 2, 3, 2, 0, 3],
+            vec![
+                (0.0, 0.0), // 0
+                (2.0, 0.0), // 1
+                (1.0, 2.0), // 2
+                (1.0, 1.0), // 3
+            ],
+            3,
+        )
+        .unwrap();
+
+        // The vertices of the boundary edge share two neighbors, but only one
+        // of them forms a triangle with the edge.
+        let ab = find_arc(&graph, ((2.0, 0.0), (0.0, 0.0))).unwrap();
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph
+                .arc_mut(ab)
+                .unwrap()
+                .collapse_with(|_, destination| *destination)
+                .err()
+                .unwrap()
+        );
+
+        // The graph is unchanged.
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(3, graph.face_count());
+
+        // Collapsing any edge of a tetrahedron folds its remaining triangles
+        // onto each other.
+        let mut graph = MeshGraph::<E3>::from_raw_buffers_with_arity(
+            vec![0u32, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2],
+            vec![
+                (0.0, 0.0, 0.0), // 0
+                (1.0, 0.0, 0.0), // 1
+                (0.0, 1.0, 0.0), // 2
+                (0.0, 0.0, 1.0), // 3
+            ],
+            3,
+        )
+        .unwrap();
+        let ab = graph.arcs().nth(0).unwrap().key();
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph
+                .arc_mut(ab)
+                .unwrap()
+                .collapse_with(|_, destination| *destination)
+                .err()
+                .unwrap()
+        );
+        assert_eq!(4, graph.face_count());
+    }
+
+    #[test]
+    fn flip_edge() {
+        // Construct a graph from two triangles that form a quadrilateral.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 0, 2, 3],
+            vec![
+                (0.0, 0.0), // 0
+                (1.0, 0.0), // 1
+                (1.0, 1.0), // 2
+                (0.0, 1.0), // 3
+            ],
+            3,
+        )
+        .unwrap();
+
+        let ab = find_arc(&graph, ((0.0, 0.0), (1.0, 1.0))).unwrap();
+        let dc = graph.arc_mut(ab).unwrap().flip().unwrap().key();
+
+        assert_eq!(find_arc(&graph, ((1.0, 0.0), (0.0, 1.0))), Some(dc));
+        assert_eq!(None, find_arc(&graph, ((0.0, 0.0), (1.0, 1.0))));
+        assert_eq!(5, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        // Boundary edges cannot be flipped.
+        let ab = find_arc(&graph, ((0.0, 0.0), (1.0, 0.0))).unwrap();
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph.arc_mut(ab).unwrap().flip().err().unwrap()
+        );
+    }
+
+    #[test]
+    fn flip_edge_with_arity_conflict() {
+        // Construct a graph with two connected quadrilaterals.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3, 0, 3, 4, 5],
+            vec![
+                (0.0, 0.0),  // 0
+                (1.0, 0.0),  // 1
+                (1.0, 1.0),  // 2
+                (0.0, 1.0),  // 3
+                (-1.0, 1.0), // 4
+                (-1.0, 0.0), // 5
+            ],
+            4,
+        )
+        .unwrap();
+
+        let ab = find_arc(&graph, ((0.0, 0.0), (0.0, 1.0))).unwrap();
+        assert_eq!(
+            GraphError::ArityConflict {
+                expected: 3,
+                actual: 4,
+            },
+            graph.arc_mut(ab).unwrap().flip().err().unwrap()
+        );
+    }
+
     #[test]
     fn remove_edge() {
         // Construct a graph with two connected quadrilaterals.
@@ -1,16 +1,18 @@
 use derivative::Derivative;
+use num::{Signed, Zero};
 use smallvec::SmallVec;
 use std::borrow::Borrow;
 use std::cmp;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 use theon::query::{Intersection, Line, Plane};
-use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
 use typenum::U3;
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
+use crate::entity::dijkstra;
 use crate::entity::storage::prelude::*;
 use crate::entity::storage::{
     AsStorage, AsStorageMut, AsStorageOf, HashStorage, IncrementalKeyer, Key,
@@ -18,19 +20,22 @@ use crate::entity::storage::{
 use crate::entity::traverse::{Adjacency, Breadth, Depth, Trace, TraceFirst, Traversal};
 use crate::entity::view::{Bind, ClosedView, Orphan, Rebind, Unbind, View};
 use crate::entity::{Entity, Payload};
+use crate::geometry::patch::Patch;
+use crate::geometry::triangulation::{self, PlaneProjection, Triangulation};
+use crate::geometry::Metric;
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
 use crate::graph::geometry::{FaceCentroid, FaceNormal, FacePlane, VertexPosition};
 use crate::graph::mutation::face::{
     self, FaceBridgeCache, FaceExtrudeCache, FaceInsertCache, FacePokeCache, FaceRemoveCache,
-    FaceSplitCache,
+    FaceReverseCache, FaceSplitCache, FaceTriangulateCache,
 };
 use crate::graph::mutation::{self, Consistent, Immediate, Mutable};
 use crate::graph::path::Path;
 use crate::graph::vertex::{Vertex, VertexKey, VertexOrphan, VertexView};
 use crate::graph::{
-    Circulator, GraphError, MeshGraph, OptionExt as _, OrphanCirculator, ResultExt as _, Selector,
-    ViewCirculator,
+    BoundaryKind, Circulator, GraphError, HoleFill, MeshGraph, OptionExt as _, OrphanCirculator,
+    ResultExt as _, Selector, ViewCirculator,
 };
 use crate::transact::{BypassOrCommit, Mutate};
 use crate::{DynamicArity, IteratorExt as _, StaticArity};
@@ -257,6 +262,84 @@ where
     pub fn arc(&self) -> ArcView<&M> {
         self.to_ref().into_arc()
     }
+
+    /// Gets the keys of the faces along the shortest path to the face with
+    /// the given key using A* search.
+    ///
+    /// Paths cross between faces that share an edge. The function `f`
+    /// computes the metric between adjacent faces and the function `h`
+    /// estimates the metric from a face to the destination. The estimate must
+    /// never exceed the metric of any path to the destination and must never
+    /// decrease by more than the metric between adjacent faces. The keys begin
+    /// with this face and end with the destination.
+    ///
+    /// See [`VertexView::shortest_path_with_heuristic`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination is not reachable or if `f` returns
+    /// a negative metric.
+    ///
+    /// [`VertexView::shortest_path_with_heuristic`]: crate::graph::VertexView::shortest_path_with_heuristic
+    pub fn shortest_path_with_heuristic<Q, F, H>(
+        &self,
+        mut key: FaceKey,
+        f: F,
+        h: H,
+    ) -> Result<Vec<FaceKey>, GraphError>
+    where
+        Q: Copy + Metric,
+        F: Fn(FaceView<&M>, FaceView<&M>) -> Q,
+        H: Fn(FaceView<&M>) -> Q,
+    {
+        let metrics = dijkstra::metrics_with_heuristic(self.to_ref(), key, f, h)?;
+        if !metrics.contains_key(&key) {
+            return Err(GraphError::TopologyUnreachable);
+        }
+        let mut keys = vec![key];
+        while let Some((Some(previous), _)) = metrics.get(&key) {
+            key = *previous;
+            keys.push(key);
+        }
+        keys.reverse();
+        Ok(keys)
+    }
+
+    /// Partitions faces by their nearest seed.
+    ///
+    /// Seeds are this face and the faces with the given keys. Returns a map
+    /// from each reachable face to its nearest seed and the metric of the
+    /// shortest path from that seed, where the function `f` computes the
+    /// metric between adjacent faces.
+    ///
+    /// See [`VertexView::partition_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a seed is not found or if `f` returns a negative
+    /// metric.
+    ///
+    /// [`VertexView::partition_with`]: crate::graph::VertexView::partition_with
+    pub fn partition_with<Q, F, I>(
+        &self,
+        keys: I,
+        f: F,
+    ) -> Result<HashMap<FaceKey, (FaceKey, Q)>, GraphError>
+    where
+        Q: Copy + Metric,
+        F: Fn(FaceView<&M>, FaceView<&M>) -> Q,
+        I: IntoIterator<Item = FaceKey>,
+    {
+        let (storage, _) = self.to_ref().unbind();
+        let seeds = Some(Ok(self.to_ref()))
+            .into_iter()
+            .chain(
+                keys.into_iter()
+                    .map(|key| FaceView::bind(storage, key).ok_or(GraphError::TopologyNotFound)),
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(dijkstra::partition_with(seeds, f)?)
+    }
 }
 
 impl<B, M, G> FaceView<B>
@@ -681,6 +764,7 @@ where
         //       Additionally, splitting may fail under certain conditions!
         //       Triangulation that ignores geometry is likely much less useful
         //       than a triangulation algorithm that considers position data.
+        //       See `try_triangulate` for a geometric triangulation.
         // TODO: This implementation differs from `MeshGraph::triangulate`,
         //       because it is not possible to retry `split` in this context.
         let mut face = self;
@@ -694,6 +778,195 @@ where
         face
     }
 
+    /// Decomposes the face into triangles using its geometry. Does nothing if
+    /// the face is triangular.
+    ///
+    /// The perimeter of the face is projected onto its best-fit plane and
+    /// triangulated via ear clipping. Unlike [`triangulate`], this produces
+    /// triangles that do not overlap for concave faces and never splits the
+    /// face along an edge that already exists in the graph.
+    ///
+    /// Returns the terminating face of the decomposition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the face is geometrically degenerate, such as when
+    /// its vertices are collinear, or if no ear can be clipped from its
+    /// perimeter.
+    ///
+    /// # Examples
+    ///
+    /// Triangulating a concave face:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers_with_arity(
+    ///     vec![0u32, 1, 2, 3, 4, 5],
+    ///     vec![
+    ///         (0.0, 0.0),
+    ///         (2.0, 0.0),
+    ///         (2.0, 1.0),
+    ///         (1.0, 1.0),
+    ///         (1.0, 2.0),
+    ///         (0.0, 2.0),
+    ///     ],
+    ///     6,
+    /// )
+    /// .unwrap();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph.face_mut(key).unwrap().try_triangulate().unwrap();
+    ///
+    /// assert_eq!(4, graph.face_count());
+    /// ```
+    ///
+    /// [`triangulate`]: crate::graph::FaceView::triangulate
+    pub fn try_triangulate(self) -> Result<Self, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        if self.arity() <= 3 {
+            return Ok(self);
+        }
+        let keys = self.adjacent_vertices().keys().collect::<Vec<_>>();
+        let points = self
+            .adjacent_vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<Vec<_>>();
+        let projection = PlaneProjection::from_points(&points).ok_or(GraphError::Geometry)?;
+        let points = points
+            .into_iter()
+            .map(|point| projection.project(point))
+            .collect::<Vec<_>>();
+        let triangles = triangulation::ear_clip_with(&points, |a, b| {
+            // Splitting along an existing edge would intersect another face.
+            let ab = (keys[a], keys[b]).into();
+            Rebind::<_, ArcView<_>>::rebind(self.to_ref(), ab).is_none()
+        })
+        .ok_or(GraphError::Geometry)?;
+        // The last triangle is the terminating face and requires no split.
+        let n = triangles.len() - 1;
+        let mut face = self;
+        for [a, _, c] in triangles.into_iter().take(n) {
+            // Ears are clipped in the winding of the face, so the arc returned
+            // by the split is in the remaining face.
+            face = face
+                .split(keys[a], keys[c])?
+                .into_face()
+                .expect_consistent();
+        }
+        Ok(face)
+    }
+
+    /// Decomposes the face into triangles via a constrained Delaunay
+    /// triangulation of its geometry.
+    ///
+    /// The perimeter of the face is projected onto its best-fit plane and
+    /// triangulated such that no vertex lies within the circumcircle of any
+    /// triangle, except where prevented by the perimeter. This avoids the
+    /// sliver triangles that are typical of fan triangulations.
+    ///
+    /// If a minimum angle (in radians) is given, then Steiner vertices are
+    /// inserted into the interior of the face at the circumcenters of
+    /// triangles with smaller angles. The perimeter of the face is never split,
+    /// so adjacent faces are unaffected, but this means that the minimum angle
+    /// is not guaranteed. Steiner vertices copy the data of a vertex in the
+    /// perimeter and are positioned in the best-fit plane of the face.
+    ///
+    /// Returns the keys of the triangular faces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the face is geometrically degenerate, such as when
+    /// its vertices are collinear, or if it cannot be triangulated without
+    /// intersecting another face.
+    ///
+    /// # Examples
+    ///
+    /// Triangulating a long hexagonal face:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers_with_arity(
+    ///     vec![0u32, 1, 2, 3, 4, 5],
+    ///     vec![
+    ///         (0.0, 0.0),
+    ///         (4.0, 0.0),
+    ///         (8.0, 0.0),
+    ///         (8.0, 1.0),
+    ///         (4.0, 1.0),
+    ///         (0.0, 1.0),
+    ///     ],
+    ///     6,
+    /// )
+    /// .unwrap();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// let faces = graph
+    ///     .face_mut(key)
+    ///     .unwrap()
+    ///     .triangulate_delaunay(None)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(4, faces.len());
+    /// ```
+    pub fn triangulate_delaunay(
+        self,
+        angle: Option<Scalar<VertexPosition<G>>>,
+    ) -> Result<Vec<FaceKey>, GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        if self.arity() == 3 && angle.is_none() {
+            return Ok(vec![self.key()]);
+        }
+        let keys = self.adjacent_vertices().keys().collect::<Vec<_>>();
+        let points = self
+            .adjacent_vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<Vec<_>>();
+        let projection = PlaneProjection::from_points(&points).ok_or(GraphError::Geometry)?;
+        let points = points
+            .into_iter()
+            .map(|point| projection.project(point))
+            .collect::<Vec<_>>();
+        let Triangulation { points, triangles } =
+            triangulation::delaunay_with(&points, angle, |a, b| {
+                let ab = (keys[a], keys[b]).into();
+                Rebind::<_, ArcView<_>>::rebind(self.to_ref(), ab).is_none()
+            })
+            .ok_or(GraphError::Geometry)?;
+        let n = keys.len();
+        let cache = FaceTriangulateCache::from_face(self.to_ref(), points.len() - n, triangles)?;
+        let data = self.arc().source_vertex().get().clone();
+        let (storage, _) = self.unbind();
+        Ok(Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| {
+                face::triangulate_with(mutation, cache, |index| {
+                    let mut data = data.clone();
+                    *data.as_position_mut() = projection.embed(points[n + index]);
+                    data
+                })
+            })
+            .map(|(_, faces)| faces)
+            .map_err(|(_, error)| error)
+            .expect_consistent())
+    }
+
     /// Subdivides the face about a vertex. A triangle fan is formed from each
     /// arc in the face's perimeter and the vertex.
     ///
@@ -859,6 +1132,31 @@ where
             .expect_consistent()
     }
 
+    /// Reverses the winding of the face.
+    ///
+    /// The ring of the face is reversed, which negates its normal. The keys
+    /// of the face and its topology are unchanged, but arc data is associated
+    /// with the direction of arcs, so the face's ring is formed from what
+    /// were its opposite arcs.
+    ///
+    /// Returns the reversed face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the face is connected to any other face by an edge
+    /// or vertex. The winding of adjacent faces must agree, so such a face
+    /// cannot be reversed alone. See [`MeshGraph::reverse`].
+    ///
+    /// [`MeshGraph::reverse`]: crate::graph::MeshGraph::reverse
+    pub fn reverse(self) -> Result<Self, GraphError> {
+        let cache = FaceReverseCache::from_face(self.to_ref())?;
+        let (storage, abc) = self.unbind();
+        Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| face::reverse(mutation, cache))
+            .map(|(storage, _)| Bind::bind(storage, abc).expect_consistent())
+            .map_err(|(_, error)| error)
+    }
+
     /// Removes the face.
     ///
     /// Returns the remaining ring of the face if it is not entirely disjoint, otherwise `None`.
@@ -1273,6 +1571,93 @@ where
     }
 }
 
+impl<B, M, G> Ring<B>
+where
+    B: Reborrow<Target = M>,
+    M: AsStorage<Arc<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    /// Gets the length of the ring. This is the sum of the lengths of its
+    /// arcs.
+    pub fn length(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        self.arcs()
+            .map(|arc| {
+                let source = *arc.source_vertex().position();
+                let destination = *arc.destination_vertex().position();
+                (destination - source).magnitude()
+            })
+            .fold(Zero::zero(), |length, magnitude| length + magnitude)
+    }
+
+    /// Determines if a boundary ring is a hole or an outer boundary.
+    ///
+    /// The ring and its adjacent faces are projected into the plane that best
+    /// fits the ring. The ring is a hole if its signed area in this plane has
+    /// the same sign as the total signed area of its adjacent faces, because
+    /// boundary arcs wind with adjacent faces about a hole and against them
+    /// about an outer boundary.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ring has a face or if the ring or its adjacent
+    /// faces are degenerate in the plane that best fits the ring.
+    pub fn boundary_kind(&self) -> Result<BoundaryKind, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        if self.face().is_some() {
+            return Err(GraphError::TopologyConflict);
+        }
+        let points = self
+            .vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<Vec<_>>();
+        let projection = PlaneProjection::from_points(&points).ok_or(GraphError::Geometry)?;
+        let area = triangulation::area(
+            &points
+                .into_iter()
+                .map(|point| projection.project(point))
+                .collect::<Vec<_>>(),
+        );
+        let mut keys = HashSet::new();
+        let reference = self
+            .arcs()
+            .flat_map(|arc| arc.into_opposite_arc().into_face())
+            .filter(|face| keys.insert(face.key()))
+            .map(|face| {
+                triangulation::area(
+                    &face
+                        .adjacent_vertices()
+                        .map(|vertex| projection.project(*vertex.position()))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .fold(Zero::zero(), |sum: Scalar<VertexPosition<G>>, area| {
+                sum + area
+            });
+        if area.is_zero() || reference.is_zero() {
+            Err(GraphError::Geometry)
+        }
+        else if area.is_positive() == reference.is_positive() {
+            Ok(BoundaryKind::Hole)
+        }
+        else {
+            Ok(BoundaryKind::Outer)
+        }
+    }
+}
+
 impl<'a, B, M, G> Ring<B>
 where
     B: ReborrowInto<'a, Target = M>,
@@ -1317,6 +1702,32 @@ where
     pub fn vertices(&self) -> impl Clone + Iterator<Item = VertexView<&B::Target>> {
         self.to_ref().into_vertices()
     }
+
+    /// Partitions vertices by their nearest vertex within the ring.
+    ///
+    /// Seeds are the vertices within the ring. Returns a map from each
+    /// reachable vertex to its nearest seed and the metric of the shortest
+    /// path from that seed, where the function `f` computes the metric
+    /// between adjacent vertices. For example, this can compute the geodesic
+    /// distance from a boundary.
+    ///
+    /// See [`VertexView::partition_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `f` returns a negative metric.
+    ///
+    /// [`VertexView::partition_with`]: crate::graph::VertexView::partition_with
+    pub fn partition_with<Q, F>(
+        &self,
+        f: F,
+    ) -> Result<HashMap<VertexKey, (VertexKey, Q)>, GraphError>
+    where
+        Q: Copy + Metric,
+        F: Fn(VertexView<&B::Target>, VertexView<&B::Target>) -> Q,
+    {
+        Ok(dijkstra::partition_with(self.vertices(), f)?)
+    }
 }
 
 impl<'a, M, G> Ring<&'a mut M>
@@ -1404,6 +1815,139 @@ where
     }
 }
 
+impl<'a, M, G> Ring<&'a mut M>
+where
+    M: AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Default
+        + Mutable<Data = G>,
+    G: GraphData,
+{
+    /// Fills the ring with triangles.
+    ///
+    /// If the ring already has a face, then no topology is inserted. Triangular
+    /// rings are filled with a single face. Otherwise, the ring is triangulated
+    /// such that the total area of the triangles is minimized. If the
+    /// [`HoleFill::Fair`] strategy is used, then the triangulation is refined
+    /// and faired so that the density and shape of the filling blends with the
+    /// ring.
+    ///
+    /// Returns the keys of the faces that fill the ring.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ring visits a vertex more than once or if no
+    /// triangulation can be formed without inserting an existing edge.
+    /// The ring is left unfilled if an error occurs.
+    ///
+    /// # Examples
+    ///
+    /// Filling the hole left by removing a face from a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{HoleFill, MeshGraph};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// let mut graph: MeshGraph<Point3<R64>> =
+    ///     Cube::new().polygons::<Position<Point3<R64>>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// let ring = graph.face_mut(key).unwrap().remove().unwrap();
+    /// let faces = ring.fill(HoleFill::Triangulate).unwrap();
+    ///
+    /// assert_eq!(2, faces.len());
+    /// assert_eq!(7, graph.face_count());
+    /// ```
+    ///
+    /// [`HoleFill::Fair`]: crate::graph::HoleFill::Fair
+    pub fn fill(self, fill: HoleFill) -> Result<Vec<FaceKey>, GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        if let Some(face) = self.face() {
+            return Ok(vec![face.key()]);
+        }
+        let keys = self.vertices().keys().collect::<Vec<_>>();
+        if keys.iter().collect::<HashSet<_>>().len() != keys.len() {
+            return Err(GraphError::TopologyConflict);
+        }
+        if keys.len() == 3 {
+            return Ok(vec![self.get_or_insert_face().key()]);
+        }
+        let n = keys.len();
+        let mut is_diagonal = |a: usize, b: usize| {
+            let ab = (keys[a], keys[b]).into();
+            Rebind::<_, ArcView<_>>::rebind(self.arc(), ab).is_none()
+        };
+        let points = self
+            .vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<Vec<_>>();
+        let mut patch = Patch::minimum_area_with(points, &mut is_diagonal)
+            .ok_or(GraphError::TopologyConflict)?;
+        if let HoleFill::Fair = fill {
+            patch.refine_with(&mut is_diagonal);
+            patch.fair();
+        }
+        let Patch {
+            points, triangles, ..
+        } = patch;
+        let data = self.arc().source_vertex().get().clone();
+        let face = self.get_or_insert_face();
+        // The vertices of the inserted face may begin anywhere in the ring, so
+        // indices into the perimeter of the patch are rotated accordingly.
+        let offset = face
+            .adjacent_vertices()
+            .keys()
+            .position(|key| key == keys[0])
+            .expect_consistent();
+        let rotate = |index: usize| {
+            if index < n {
+                (index + offset) % n
+            }
+            else {
+                index
+            }
+        };
+        let triangles = triangles
+            .into_iter()
+            .map(|triangle| triangle.map(rotate))
+            .collect::<Vec<_>>();
+        let cache =
+            match FaceTriangulateCache::from_face(face.to_ref(), points.len() - n, triangles) {
+                Ok(cache) => cache,
+                Err(error) => {
+                    // Remove the inserted face so that the ring is left unfilled.
+                    face.remove().expect_consistent();
+                    return Err(error);
+                }
+            };
+        let (storage, _) = face.unbind();
+        Ok(Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| {
+                face::triangulate_with(mutation, cache, |index| {
+                    let mut data = data.clone();
+                    *data.as_position_mut() = points[n + index];
+                    data
+                })
+            })
+            .map(|(_, faces)| faces)
+            .map_err(|(_, error)| error)
+            .expect_consistent())
+    }
+}
+
 impl<B, M, G> DynamicArity for Ring<B>
 where
     B: Reborrow<Target = M>,
@@ -1804,13 +2348,13 @@ mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
 
-    use crate::graph::MeshGraph;
+    use crate::graph::{GraphError, HoleFill, MeshGraph};
     use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
-    use crate::primitive::Tetragon;
+    use crate::primitive::{Tetragon, Trigon};
 
     type E2 = Point2<R64>;
     type E3 = Point3<R64>;
@@ -1981,6 +2525,157 @@ mod tests {
         assert_eq!(12, graph.face_count());
     }
 
+    #[test]
+    fn try_triangulate_concave_face() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3, 4, 5],
+            vec![
+                (0.0, 0.0),
+                (2.0, 0.0),
+                (2.0, 1.0),
+                (1.0, 1.0), // Reflex.
+                (1.0, 2.0),
+                (0.0, 2.0),
+            ],
+            6,
+        )
+        .unwrap();
+        graph.try_triangulate().unwrap();
+
+        assert_eq!(4, graph.face_count());
+        assert_eq!(9, graph.edge_count());
+        // No triangle is inverted with respect to the original face.
+        for face in graph.faces() {
+            let points = face
+                .adjacent_vertices()
+                .map(|vertex| *vertex.position())
+                .collect::<Vec<_>>();
+            let (a, b, c) = (points[0], points[1], points[2]);
+            assert!(((b.x - a.x) * (c.y - a.y)) - ((b.y - a.y) * (c.x - a.x)) > 0.0);
+        }
+    }
+
+    #[test]
+    fn try_triangulate_degenerate_face() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3],
+            vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0)],
+            4,
+        )
+        .unwrap();
+
+        assert_eq!(Err(GraphError::Geometry), graph.try_triangulate());
+    }
+
+    #[test]
+    fn triangulate_delaunay_refined_face() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers_with_arity(
+            (0u32..32).collect::<Vec<_>>(),
+            (0..32)
+                .map(|n| {
+                    let theta = f64::from(n) * std::f64::consts::PI / 16.0;
+                    (theta.cos(), theta.sin())
+                })
+                .collect::<Vec<_>>(),
+            32,
+        )
+        .unwrap();
+        graph
+            .triangulate_delaunay(Some(20.0f64.to_radians()))
+            .unwrap();
+
+        // Steiner vertices are inserted into the interior of the face.
+        assert!(graph.vertex_count() > 32);
+        // Triangulating a disk inserts two faces for each interior vertex.
+        assert_eq!(30 + (2 * (graph.vertex_count() - 32)), graph.face_count());
+        for face in graph.faces() {
+            assert_eq!(3, face.arity());
+            let points = face
+                .adjacent_vertices()
+                .map(|vertex| *vertex.position())
+                .collect::<Vec<_>>();
+            let (a, b, c) = (points[0], points[1], points[2]);
+            assert!(((b.x - a.x) * (c.y - a.y)) - ((b.y - a.y) * (c.x - a.x)) > 0.0);
+        }
+    }
+
+    #[test]
+    fn fill_ring() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers_with_arity(
+            (0u32..32).collect::<Vec<_>>(),
+            (0..32)
+                .map(|n| {
+                    let theta = f64::from(n) * std::f64::consts::PI / 16.0;
+                    (theta.cos(), theta.sin())
+                })
+                .collect::<Vec<_>>(),
+            32,
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().arc().opposite_arc().key();
+        let faces = graph
+            .arc_mut(key)
+            .unwrap()
+            .into_ring()
+            .fill(HoleFill::Fair)
+            .unwrap();
+
+        // Refinement inserts vertices into the interior of the ring.
+        assert!(graph.vertex_count() > 32);
+        assert_eq!(faces.len() + 1, graph.face_count());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        // The filled graph is closed and so its Euler characteristic is two.
+        assert_eq!(
+            2,
+            (graph.vertex_count() + graph.face_count()) - graph.edge_count()
+        );
+        for face in faces {
+            assert_eq!(3, graph.face(face).unwrap().arity());
+        }
+    }
+
+    #[test]
+    fn fill_ring_with_face() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        let faces = graph
+            .face_mut(key)
+            .unwrap()
+            .into_ring()
+            .fill(HoleFill::Triangulate)
+            .unwrap();
+
+        assert_eq!(vec![key], faces);
+        assert_eq!(6, graph.face_count());
+    }
+
+    #[test]
+    fn reverse_face() {
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)],
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        let normal = graph.face(key).unwrap().normal().unwrap();
+        let face = graph.face_mut(key).unwrap().reverse().unwrap();
+
+        assert_eq!(-normal, face.normal().unwrap());
+        assert!(graph.validate().is_empty());
+        assert_eq!(3, graph.boundaries().nth(0).unwrap().arity());
+    }
+
+    #[test]
+    fn reverse_face_with_adjacent_face() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+
+        assert_eq!(
+            Err(GraphError::TopologyConflict),
+            graph.face_mut(key).unwrap().reverse().map(|_| ())
+        );
+    }
+
     #[test]
     fn logical_metrics() {
         let graph = MeshGraph::<Point2<f32>>::from_raw_buffers_with_arity(
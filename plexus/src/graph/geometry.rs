@@ -7,21 +7,26 @@
 // necessary, constraints are specified there so that they do not pollute user
 // code.
 
+use decorum::Real;
+use num::{NumCast, One, Zero};
+use smallvec::SmallVec;
+use std::f64::consts::PI;
 use theon::ops::{Cross, Interpolate, Project};
 use theon::query::Plane;
-use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Vector, VectorSpace};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector, VectorSpace};
 use theon::{AsPosition, Position};
 use typenum::U3;
 
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::AsStorage;
+use crate::geometry::patch::cross_magnitude;
 use crate::graph::data::{GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcView, Edge, ToArc};
 use crate::graph::face::{Face, ToRing};
 use crate::graph::mutation::Consistent;
 use crate::graph::vertex::{Vertex, VertexView};
 use crate::graph::{GraphError, OptionExt as _, ResultExt as _};
-use crate::IteratorExt as _;
+use crate::{DynamicArity, IteratorExt as _};
 
 pub type VertexPosition<G> = Position<<G as GraphData>::Vertex>;
 
@@ -96,6 +101,307 @@ where
     }
 }
 
+/// Principal curvatures and directions of a vertex.
+///
+/// See [`VertexPrincipalCurvature`].
+///
+/// [`VertexPrincipalCurvature`]: crate::graph::VertexPrincipalCurvature
+#[derive(Clone, Copy)]
+pub struct PrincipalCurvatures<S>
+where
+    S: EuclideanSpace,
+{
+    /// The maximum normal curvature.
+    pub maximum: Scalar<S>,
+    /// The minimum normal curvature.
+    pub minimum: Scalar<S>,
+    /// The unit tangent in the direction of maximum curvature.
+    pub maximum_direction: Vector<S>,
+    /// The unit tangent in the direction of minimum curvature, which is
+    /// orthogonal to the direction of maximum curvature.
+    pub minimum_direction: Vector<S>,
+}
+
+/// Mixed Voronoi area of a vertex.
+///
+/// This is the area of the region of the adjacent triangles that is closer to
+/// the vertex than to any other vertex. The circumcenter of an obtuse triangle
+/// lies outside of the triangle, so a fixed fraction of the area of obtuse
+/// triangles is used instead. The areas of all vertices sum to the area of
+/// the surface. Faces must be triangles.
+pub trait VertexArea: GraphData
+where
+    Self::Vertex: AsPosition,
+    VertexPosition<Self>: EuclideanSpace,
+{
+    fn area<B>(vertex: VertexView<B>) -> Result<Scalar<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>;
+}
+
+impl<G> VertexArea for G
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: InnerSpace,
+{
+    fn area<B>(vertex: VertexView<B>) -> Result<Scalar<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+    {
+        let zero = Zero::zero();
+        let two = <Scalar<VertexPosition<Self>> as One>::one() + One::one();
+        let area = triangles::<_, G>(vertex)?
+            .into_iter()
+            .map(|[p, q, r]| {
+                let (cp, cq, cr) = (cotangent(q, r, p), cotangent(r, p, q), cotangent(p, q, r));
+                if cp < zero {
+                    triangle_area(p, q, r) / two
+                }
+                else if cq < zero || cr < zero {
+                    triangle_area(p, q, r) / (two * two)
+                }
+                else {
+                    let (pq, pr) = (q - p, r - p);
+                    ((pr.dot(pr) * cq) + (pq.dot(pq) * cr)) / (two * two * two)
+                }
+            })
+            .fold(zero, |sum, area| sum + area);
+        if area > zero {
+            Ok(area)
+        }
+        else {
+            Err(GraphError::Geometry)
+        }
+    }
+}
+
+/// Gaussian curvature of a vertex.
+///
+/// The curvature is approximated by the angle defect of the vertex, which is
+/// the difference between $2\pi$ (or $\pi$ along boundaries) and the sum of
+/// the angles of the adjacent triangles at the vertex, divided by the area of
+/// the vertex. See [`VertexArea`].
+///
+/// [`VertexArea`]: crate::graph::VertexArea
+pub trait VertexGaussianCurvature: VertexArea
+where
+    Self::Vertex: AsPosition,
+    VertexPosition<Self>: EuclideanSpace,
+{
+    fn gaussian_curvature<B>(
+        vertex: VertexView<B>,
+    ) -> Result<Scalar<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>;
+}
+
+impl<G> VertexGaussianCurvature for G
+where
+    G: VertexArea,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: InnerSpace,
+    Scalar<VertexPosition<G>>: NumCast,
+{
+    fn gaussian_curvature<B>(
+        vertex: VertexView<B>,
+    ) -> Result<Scalar<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+    {
+        let area = G::area(vertex.to_ref())?;
+        let angles = triangles::<_, G>(vertex.to_ref())?
+            .into_iter()
+            .map(|[p, q, r]| angle(q, r, p))
+            .fold(Zero::zero(), |sum: Scalar<VertexPosition<Self>>, angle| {
+                sum + angle
+            });
+        let pi = <Scalar<VertexPosition<Self>> as NumCast>::from(PI).unwrap();
+        let total = if vertex.outgoing_arcs().any(|arc| arc.is_boundary_arc()) {
+            pi
+        }
+        else {
+            pi + pi
+        };
+        Ok((total - angles) / area)
+    }
+}
+
+/// Mean curvature of a vertex.
+///
+/// The curvature is approximated by the cotangent Laplacian of the vertex,
+/// which is normalized by the area of the vertex (see [`VertexArea`]). The
+/// curvature is positive where the surface bends away from the normal of the
+/// vertex, such as on a sphere with outward normals.
+///
+/// [`VertexArea`]: crate::graph::VertexArea
+pub trait VertexMeanCurvature: VertexArea + VertexNormal
+where
+    Self::Vertex: AsPosition,
+    VertexPosition<Self>: EuclideanSpace,
+{
+    fn mean_curvature<B>(vertex: VertexView<B>) -> Result<Scalar<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Self>>
+            + AsStorage<Face<Self>>
+            + AsStorage<Vertex<Self>>
+            + Consistent
+            + Parametric<Data = Self>;
+}
+
+impl<G> VertexMeanCurvature for G
+where
+    G: VertexArea + VertexNormal,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: InnerSpace,
+{
+    fn mean_curvature<B>(vertex: VertexView<B>) -> Result<Scalar<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Self>>
+            + AsStorage<Face<Self>>
+            + AsStorage<Vertex<Self>>
+            + Consistent
+            + Parametric<Data = Self>,
+    {
+        let area = G::area(vertex.to_ref())?;
+        let normal = <G as VertexNormal>::normal(vertex.to_ref())?;
+        // The edge $\overline{PQ}$ is opposite the corner at $R$ and the edge
+        // $\overline{PR}$ is opposite the corner at $Q$.
+        let laplacian = triangles::<_, G>(vertex.to_ref())?.into_iter().fold(
+            Zero::zero(),
+            |sum: Vector<VertexPosition<Self>>, [p, q, r]| {
+                sum + ((p - q) * cotangent(p, q, r)) + ((p - r) * cotangent(r, p, q))
+            },
+        );
+        let two = <Scalar<VertexPosition<Self>> as One>::one() + One::one();
+        let curvature = laplacian.magnitude() / (two * two * area);
+        Ok(if laplacian.dot(normal) < Zero::zero() {
+            -curvature
+        }
+        else {
+            curvature
+        })
+    }
+}
+
+/// Principal curvatures and directions of a vertex.
+///
+/// The principal curvatures are derived from the Gaussian and mean curvatures
+/// of the vertex (see [`VertexGaussianCurvature`] and [`VertexMeanCurvature`]).
+/// The principal directions are derived from a curvature tensor that is fit
+/// to the normal curvatures along the edges of the vertex via least squares,
+/// so the vertex must have at least three adjacent vertices.
+///
+/// [`VertexGaussianCurvature`]: crate::graph::VertexGaussianCurvature
+/// [`VertexMeanCurvature`]: crate::graph::VertexMeanCurvature
+pub trait VertexPrincipalCurvature: VertexGaussianCurvature + VertexMeanCurvature
+where
+    Self::Vertex: AsPosition,
+    VertexPosition<Self>: EuclideanSpace,
+{
+    fn principal_curvatures<B>(
+        vertex: VertexView<B>,
+    ) -> Result<PrincipalCurvatures<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Self>>
+            + AsStorage<Face<Self>>
+            + AsStorage<Vertex<Self>>
+            + Consistent
+            + Parametric<Data = Self>;
+}
+
+impl<G> VertexPrincipalCurvature for G
+where
+    G: VertexGaussianCurvature + VertexMeanCurvature,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+    Scalar<VertexPosition<G>>: NumCast,
+{
+    fn principal_curvatures<B>(
+        vertex: VertexView<B>,
+    ) -> Result<PrincipalCurvatures<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Self>>
+            + AsStorage<Face<Self>>
+            + AsStorage<Vertex<Self>>
+            + Consistent
+            + Parametric<Data = Self>,
+    {
+        let zero = Zero::zero();
+        let two = <Scalar<VertexPosition<Self>> as One>::one() + One::one();
+        let gaussian = G::gaussian_curvature(vertex.to_ref())?;
+        let mean = G::mean_curvature(vertex.to_ref())?;
+        let discriminant = (mean * mean) - gaussian;
+        let root = if discriminant > zero {
+            discriminant.sqrt()
+        }
+        else {
+            zero
+        };
+
+        // Construct an orthonormal basis $(U,V)$ of the tangent plane and fit
+        // the symmetric tensor $\begin{bmatrix}a&b\\b&c\end{bmatrix}$ to
+        // the normal curvatures along the tangents of the edges.
+        let normal = <G as VertexNormal>::normal(vertex.to_ref())?;
+        let position = *vertex.position();
+        let tangent = |offset: Vector<VertexPosition<Self>>| {
+            (offset - (normal * normal.dot(offset))).normalize()
+        };
+        let u = vertex
+            .adjacent_vertices()
+            .find_map(|adjacent| tangent(*adjacent.position() - position))
+            .ok_or(GraphError::Geometry)?;
+        let v = normal.cross(u);
+        let mut m = [[zero; 3]; 3];
+        let mut n = [zero; 3];
+        for adjacent in vertex.adjacent_vertices() {
+            let offset = *adjacent.position() - position;
+            let t = match tangent(offset) {
+                Some(t) => t,
+                _ => continue,
+            };
+            let (x, y) = (t.dot(u), t.dot(v));
+            let curvature = -(two * offset.dot(normal)) / offset.dot(offset);
+            let w = [x * x, two * x * y, y * y];
+            for (row, wi) in m.iter_mut().zip(w.iter()) {
+                for (entry, wj) in row.iter_mut().zip(w.iter()) {
+                    *entry = *entry + (*wi * *wj);
+                }
+            }
+            for (entry, wi) in n.iter_mut().zip(w.iter()) {
+                *entry = *entry + (*wi * curvature);
+            }
+        }
+        let [a, b, c] = solve(m, n).ok_or(GraphError::Geometry)?;
+        // The direction of maximum curvature is the eigenvector of the tensor
+        // with the greatest eigenvalue.
+        let theta = (two * b).atan2(a - c) / two;
+        let (sine, cosine) = (theta.sin(), theta.cos());
+        Ok(PrincipalCurvatures {
+            maximum: mean + root,
+            minimum: mean - root,
+            maximum_direction: (u * cosine) + (v * sine),
+            minimum_direction: (v * cosine) - (u * sine),
+        })
+    }
+}
+
 pub trait ArcNormal: GraphData
 where
     Self::Vertex: AsPosition,
@@ -220,7 +526,7 @@ where
 
 impl<G> FaceNormal for G
 where
-    G: FaceCentroid + GraphData,
+    G: GraphData,
     G::Vertex: AsPosition,
     Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
     VertexPosition<G>: EuclideanSpace,
@@ -232,17 +538,12 @@ where
             AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
         T: ToRing<B>,
     {
-        let ring = ring.into_ring();
-        let (a, b) = ring
+        let positions = ring
+            .into_ring()
             .vertices()
-            .take(2)
             .map(|vertex| *vertex.position())
-            .try_collect()
-            .expect_consistent();
-        let c = G::centroid(ring)?;
-        let ab = a - b;
-        let bc = b - c;
-        ab.cross(bc).normalize().ok_or(GraphError::Geometry)
+            .collect::<SmallVec<[_; 4]>>();
+        normal(&positions)
     }
 }
 
@@ -259,6 +560,120 @@ where
         T: ToRing<B>;
 }
 
+// Computes the normal of a polygon from the positions of its vertices. This is
+// the normal used by `FaceNormal`, but positions need not belong to a face.
+pub(in crate::graph) fn normal<S>(positions: &[S]) -> Result<Vector<S>, GraphError>
+where
+    S: EuclideanSpace,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    if let [a, b, ..] = *positions {
+        let c = S::centroid(positions.iter().cloned()).expect_consistent();
+        let ab = a - b;
+        let bc = b - c;
+        ab.cross(bc).normalize().ok_or(GraphError::Geometry)
+    }
+    else {
+        Err(GraphError::Geometry)
+    }
+}
+
+// Gets the positions of the triangles that are adjacent to a vertex. Each
+// triangle begins with the position of the vertex and follows the winding of
+// its face.
+fn triangles<B, G>(vertex: VertexView<B>) -> Result<Vec<[VertexPosition<G>; 3]>, GraphError>
+where
+    B: Reborrow,
+    B::Target: AsStorage<Arc<G>> + AsStorage<Vertex<G>> + Consistent + Parametric<Data = G>,
+    G: GraphData,
+    G::Vertex: AsPosition,
+{
+    vertex
+        .outgoing_arcs()
+        .filter(|arc| !arc.is_boundary_arc())
+        .map(|arc| {
+            let arity = arc.ring().arity();
+            if arity != 3 {
+                return Err(GraphError::ArityConflict {
+                    expected: 3,
+                    actual: arity,
+                });
+            }
+            let next = arc.next_arc();
+            Ok([
+                *arc.source_vertex().position(),
+                *next.source_vertex().position(),
+                *next.destination_vertex().position(),
+            ])
+        })
+        .collect()
+}
+
+// Gets the interior angle at $C$ in the triangle $ABC$.
+fn angle<S>(a: S, b: S, c: S) -> Scalar<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+{
+    let (u, v) = (a - c, b - c);
+    let dot = u.dot(v);
+    cross_magnitude::<S>(u, v).atan2(dot)
+}
+
+// Gets the cotangent of the angle at $C$ in the triangle $ABC$. Degenerate
+// angles have a cotangent of zero.
+pub(in crate::graph) fn cotangent<S>(a: S, b: S, c: S) -> Scalar<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+{
+    let (u, v) = (a - c, b - c);
+    let sine = cross_magnitude::<S>(u, v);
+    if sine > Zero::zero() {
+        u.dot(v) / sine
+    }
+    else {
+        Zero::zero()
+    }
+}
+
+fn triangle_area<S>(a: S, b: S, c: S) -> Scalar<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+{
+    let two = <Scalar<S> as One>::one() + One::one();
+    cross_magnitude::<S>(b - a, c - a) / two
+}
+
+// Solves a symmetric system of three linear equations via Cramer's rule.
+// Returns `None` if the system is (nearly) singular.
+pub(in crate::graph) fn solve<T>(m: [[T; 3]; 3], n: [T; 3]) -> Option<[T; 3]>
+where
+    T: NumCast + Real,
+{
+    let determinant = |m: [[T; 3]; 3]| {
+        (m[0][0] * ((m[1][1] * m[2][2]) - (m[1][2] * m[2][1])))
+            - (m[0][1] * ((m[1][0] * m[2][2]) - (m[1][2] * m[2][0])))
+            + (m[0][2] * ((m[1][0] * m[2][1]) - (m[1][1] * m[2][0])))
+    };
+    let d = determinant(m);
+    let scale = m[0][0] + m[1][1] + m[2][2];
+    let epsilon = <T as NumCast>::from(1e-9).unwrap();
+    if d.abs() <= epsilon * scale * scale * scale {
+        return None;
+    }
+    let mut solution = [T::zero(); 3];
+    for (column, x) in solution.iter_mut().enumerate() {
+        let mut m = m;
+        for (row, n) in m.iter_mut().zip(n.iter()) {
+            row[column] = *n;
+        }
+        *x = determinant(m) / d;
+    }
+    Some(solution)
+}
+
 // TODO: The `lapack` feature depends on `ndarray-linalg` and Intel MKL. MKL is
 //       dynamically linked, but the linkage fails during doctests and may fail
 //       when launching a binary. The `lapack` feature and this implementation
@@ -0,0 +1,314 @@
+//! Merging of coplanar faces.
+
+use decorum::Real;
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::AsPosition;
+
+use crate::graph::data::GraphData;
+use crate::graph::edge::ArcKey;
+use crate::graph::face::FaceKey;
+use crate::graph::geometry::{FaceNormal, VertexPosition};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph, OptionExt as _};
+use crate::{DynamicArity, IteratorExt as _};
+
+/// Merges adjacent coplanar faces and removes vertices along straight edges.
+///
+/// Returns the number of faces that have been removed.
+pub fn merge_coplanar_faces_with<G, F>(
+    graph: &mut MeshGraph<G>,
+    angle: Scalar<VertexPosition<G>>,
+    mut f: F,
+) -> usize
+where
+    G: FaceNormal,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: InnerSpace,
+    F: FnMut(&G::Face, &G::Face) -> G::Face,
+{
+    let cosine = angle.cos();
+    let n = graph.face_count();
+    // Faces with degenerate geometry have no normal and are never merged.
+    let mut normals = graph
+        .faces()
+        .flat_map(|face| face.normal().ok().map(|normal| (face.key(), normal)))
+        .collect::<HashMap<_, _>>();
+    let mut rejected = HashSet::new();
+    let mut keys = normals.keys().cloned().collect::<Vec<_>>();
+    while let Some(key) = keys.pop() {
+        let (normal, adjacent) = match (normals.get(&key), graph.face(key)) {
+            (Some(normal), Some(face)) => (
+                *normal,
+                face.adjacent_faces().keys().find(|adjacent| {
+                    !rejected.contains(&(key, *adjacent))
+                        && normals
+                            .get(adjacent)
+                            .map_or(false, |other| normal.dot(*other) >= cosine)
+                }),
+            ),
+            _ => continue,
+        };
+        let adjacent = match adjacent {
+            Some(adjacent) => adjacent,
+            _ => continue,
+        };
+        match merge_faces_with(graph, key, adjacent, &mut f) {
+            Ok(merged) => {
+                normals.remove(&key);
+                normals.remove(&adjacent);
+                normals.insert(merged, normal);
+                keys.push(merged);
+            }
+            Err(_) => {
+                rejected.insert((key, adjacent));
+                rejected.insert((adjacent, key));
+                keys.push(key);
+            }
+        }
+    }
+    // Remove vertices along straight edges of faces. Triangles are skipped,
+    // because such vertices are degenerate apexes.
+    let keys = graph.vertices().keys().collect::<Vec<_>>();
+    for key in keys {
+        let ab = match graph.vertex(key) {
+            Some(vertex) => {
+                let arcs = vertex.outgoing_arcs().collect::<SmallVec<[_; 2]>>();
+                if arcs.len() != 2 || vertex.adjacent_faces().any(|face| face.arity() == 3) {
+                    continue;
+                }
+                let a = *vertex.position();
+                let b = *arcs[0].destination_vertex().position();
+                let c = *arcs[1].destination_vertex().position();
+                match ((b - a).normalize(), (a - c).normalize()) {
+                    (Some(ab), Some(ca)) if ab.dot(ca) >= cosine => arcs[0].key(),
+                    _ => continue,
+                }
+            }
+            _ => continue,
+        };
+        // Collapsing fails if it would create non-manifold topology, such
+        // as when the neighbors of the vertex are already connected. Such
+        // vertices are kept.
+        let _ = graph
+            .arc_mut(ab)
+            .expect_consistent()
+            .collapse_with(|_, destination| destination.clone());
+    }
+    n - graph.face_count()
+}
+
+// Merges a face with an adjacent face that shares a contiguous path of one
+// or more edges and no other vertices. Vertices along the interior of the
+// path are only connected to these edges and are collapsed, which removes
+// triangles that degenerate and otherwise leaves the faces sharing a
+// single edge. Returns the key of the merged face.
+fn merge_faces_with<G, F>(
+    graph: &mut MeshGraph<G>,
+    source: FaceKey,
+    destination: FaceKey,
+    f: &mut F,
+) -> Result<FaceKey, GraphError>
+where
+    G: GraphData,
+    F: FnMut(&G::Face, &G::Face) -> G::Face,
+{
+    let data = {
+        let source = graph.face(source).ok_or(GraphError::TopologyNotFound)?;
+        let destination = graph
+            .face(destination)
+            .ok_or(GraphError::TopologyNotFound)?;
+        let shared = source
+            .adjacent_arcs()
+            .filter(|arc| {
+                arc.opposite_arc().face().map(|face| face.key()) == Some(destination.key())
+            })
+            .count();
+        let vertices = destination
+            .adjacent_vertices()
+            .keys()
+            .collect::<HashSet<_>>();
+        if shared == 0
+            || source
+                .adjacent_vertices()
+                .keys()
+                .filter(|key| vertices.contains(key))
+                .count()
+                != shared + 1
+        {
+            return Err(GraphError::TopologyConflict);
+        }
+        f(source.get(), destination.get())
+    };
+    let (mut source, mut destination) = (source, destination);
+    loop {
+        let face = graph.face(source).expect_consistent();
+        let shared = face
+            .adjacent_arcs()
+            .filter(|arc| arc.opposite_arc().face().map(|face| face.key()) == Some(destination))
+            .map(|arc| arc.key())
+            .collect::<Vec<_>>();
+        if shared.len() == 1 {
+            break;
+        }
+        // Collapse a vertex along the interior of the path.
+        let bc = face
+            .adjacent_arcs()
+            .filter(|arc| shared.contains(&arc.key()))
+            .map(|arc| arc.next_arc().key())
+            .find(|bc| shared.contains(bc))
+            .expect_consistent();
+        let (b, _): (VertexKey, VertexKey) = bc.into();
+        // Find arcs that are not connected to the collapsed vertex, which
+        // remain in the rings of the faces after the collapse.
+        let anchors = [source, destination]
+            .iter()
+            .map(|key| {
+                graph
+                    .face(*key)
+                    .expect_consistent()
+                    .adjacent_arcs()
+                    .keys()
+                    .find(|xy| {
+                        let (x, y): (VertexKey, VertexKey) = (*xy).into();
+                        x != b && y != b
+                    })
+                    .expect_consistent()
+            })
+            .collect::<SmallVec<[_; 2]>>();
+        graph
+            .arc_mut(bc)
+            .expect_consistent()
+            .collapse_with(|_, destination| destination.clone())?;
+        let find = |graph: &MeshGraph<G>, key: ArcKey| {
+            graph
+                .arc(key)
+                .and_then(|arc| arc.into_face())
+                .map(|face| face.key())
+                .expect_consistent()
+        };
+        source = find(graph, anchors[0]);
+        destination = find(graph, anchors[1]);
+        // A triangle along the path degenerates and is removed, leaving
+        // the other face.
+        if source == destination {
+            *graph.face_mut(source).expect_consistent().get_mut() = data;
+            return Ok(source);
+        }
+    }
+    let mut face = graph
+        .face_mut(source)
+        .expect_consistent()
+        .merge(destination)?;
+    *face.get_mut() = data;
+    Ok(face.key())
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::{GraphData, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::NGon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn merge_coplanar_faces() {
+        // Construct a graph resembling the following diagram.
+        //
+        //   3---4---5
+        //   |  /|  /|
+        //   | / | / |
+        //   |/  |/  |
+        //   0---1---2
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![
+                NGon([0u32, 1, 4]),
+                NGon([0, 4, 3]),
+                NGon([1, 2, 5]),
+                NGon([1, 5, 4]),
+            ],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (2.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(3, graph.merge_coplanar_faces(1e-3));
+        assert!(graph.validate().is_empty());
+        // Vertices along the straight edges of the merged face are removed.
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+        assert_eq!(4, graph.faces().nth(0).unwrap().arity());
+
+        // Faces that are not coplanar are not merged.
+        let mut graph: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .collect();
+
+        assert_eq!(6, graph.merge_coplanar_faces(1e-3.into()));
+        assert_eq!(6, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+    }
+
+    #[test]
+    fn merge_coplanar_triangle_fan() {
+        enum FaceCount {}
+
+        impl GraphData for FaceCount {
+            type Vertex = Point3<f64>;
+            type Arc = ();
+            type Edge = ();
+            type Face = u64;
+        }
+
+        // Construct a graph resembling the following diagram.
+        //
+        //   3-------2
+        //   |\     /|
+        //   | \   / |
+        //   |   4   |
+        //   | /   \ |
+        //   |/     \|
+        //   0-------1
+        let mut graph = MeshGraph::<FaceCount>::from_raw_buffers(
+            vec![
+                NGon([0u32, 1, 4]),
+                NGon([1, 2, 4]),
+                NGon([2, 3, 4]),
+                NGon([3, 0, 4]),
+            ],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (0.5, 0.5, 0.0),
+            ],
+        )
+        .unwrap();
+        for mut face in graph.face_orphans() {
+            *face.get_mut() = 1;
+        }
+
+        // Merging the last triangle into the fan removes the center vertex.
+        assert_eq!(3, graph.merge_coplanar_faces_with(1e-3, |a, b| a + b));
+        assert!(graph.validate().is_empty());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+        assert_eq!(4, *graph.faces().nth(0).unwrap().get());
+    }
+}
@@ -245,19 +245,28 @@
 //! [`MeshGraph`]: crate::graph::MeshGraph
 //! [`UvSphere`]: crate::primitive::sphere::UvSphere
 
+pub mod conway;
+
 mod builder;
 mod core;
 mod data;
+mod decimation;
 mod edge;
 mod face;
 mod geometry;
+mod merging;
 mod mutation;
 mod path;
+mod remeshing;
+mod repair;
+mod smoothing;
+mod subdivision;
+mod validation;
 mod vertex;
 
 use decorum::cmp::IntrinsicOrd;
 use decorum::R64;
-use num::{Integer, NumCast, ToPrimitive, Unsigned};
+use num::{Integer, NumCast, Signed, ToPrimitive, Unsigned, Zero};
 use smallvec::SmallVec;
 use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
@@ -268,11 +277,12 @@ use std::iter::FromIterator;
 use std::mem;
 use std::vec;
 use theon::adjunct::Map;
+use theon::ops::Cross;
 use theon::query::Aabb;
-use theon::space::{EuclideanSpace, Scalar};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
 use thiserror::Error;
-use typenum::NonZero;
+use typenum::{NonZero, U3};
 
 use crate::buffer::{BufferError, FromRawBuffers, FromRawBuffersWithArity, MeshBuffer};
 use crate::builder::{Buildable, FacetBuilder, MeshBuilder, SurfaceBuilder};
@@ -283,30 +293,35 @@ use crate::entity::storage::prelude::*;
 use crate::entity::storage::{AsStorage, AsStorageMut, AsStorageOf, Key, StorageTarget};
 use crate::entity::view::{Bind, Orphan, View};
 use crate::entity::{Entity, EntityError, Payload};
+use crate::geometry::triangulation::PlaneProjection;
 use crate::geometry::{FromGeometry, IntoGeometry};
 use crate::graph::builder::GraphBuilder;
 use crate::graph::core::{Core, OwnedCore};
 use crate::graph::data::Parametric;
 use crate::graph::edge::{Arc, Edge};
 use crate::graph::face::Face;
-use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::face::{FaceInsertCache, FaceReverseCache};
+use crate::graph::mutation::path::PathSplitCache;
 use crate::graph::mutation::{Consistent, Immediate};
 use crate::graph::vertex::Vertex;
 use crate::index::{Flat, FromIndexer, Grouping, HashIndexer, IndexBuffer, IndexVertices, Indexer};
 use crate::primitive::decompose::IntoVertices;
 use crate::primitive::{IntoPolygons, Polygonal, UnboundedPolygon};
-use crate::transact::Transact;
-use crate::{DynamicArity, MeshArity, StaticArity};
+use crate::transact::{BypassOrCommit, Mutate, Transact};
+use crate::{DynamicArity, IteratorExt as _, MeshArity, StaticArity};
 
 pub use crate::entity::view::{ClosedView, Rebind};
 pub use crate::graph::data::GraphData;
 pub use crate::graph::edge::{ArcKey, ArcOrphan, ArcView, EdgeKey, EdgeOrphan, EdgeView, ToArc};
 pub use crate::graph::face::{FaceKey, FaceOrphan, FaceView, Ring, ToRing};
 pub use crate::graph::geometry::{
-    ArcNormal, EdgeMidpoint, FaceCentroid, FaceNormal, FacePlane, VertexCentroid, VertexNormal,
-    VertexPosition,
+    ArcNormal, EdgeMidpoint, FaceCentroid, FaceNormal, FacePlane, PrincipalCurvatures, VertexArea,
+    VertexCentroid, VertexGaussianCurvature, VertexMeanCurvature, VertexNormal, VertexPosition,
+    VertexPrincipalCurvature,
 };
 pub use crate::graph::path::Path;
+pub use crate::graph::repair::Repair;
+pub use crate::graph::validation::Defect;
 pub use crate::graph::vertex::{VertexKey, VertexOrphan, VertexView};
 
 pub use Selector::ByIndex;
@@ -585,6 +600,143 @@ impl From<FaceKey> for GraphKey {
     }
 }
 
+/// Policy for boundary vertices when constructing the dual of a graph.
+///
+/// See [`MeshGraph::to_dual`].
+///
+/// [`MeshGraph::to_dual`]: crate::graph::MeshGraph::to_dual
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DualBoundary {
+    /// No faces are inserted for boundary vertices. The dual is bounded by
+    /// the faces of interior vertices.
+    Skip,
+    /// Faces are inserted for boundary vertices and are closed by vertices at
+    /// the midpoints of boundary edges and at the boundary vertices. The dual
+    /// covers the same region as the graph.
+    Close,
+}
+
+/// Strategy for filling holes in a graph.
+///
+/// See [`MeshGraph::fill_holes`] and [`Ring::fill`].
+///
+/// [`MeshGraph::fill_holes`]: crate::graph::MeshGraph::fill_holes
+/// [`Ring::fill`]: crate::graph::Ring::fill
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HoleFill {
+    /// Holes are triangulated such that the total area of the inserted faces
+    /// is minimized. No vertices are inserted.
+    Triangulate,
+    /// Holes are triangulated as with [`HoleFill::Triangulate`] and then
+    /// refined and faired. Vertices are inserted into the interior of holes
+    /// such that the density and shape of the filling blends with the
+    /// surrounding surface.
+    ///
+    /// [`HoleFill::Triangulate`]: crate::graph::HoleFill::Triangulate
+    Fair,
+}
+
+/// Classification of boundary rings.
+///
+/// See [`Ring::boundary_kind`].
+///
+/// [`Ring::boundary_kind`]: crate::graph::Ring::boundary_kind
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BoundaryKind {
+    /// The boundary encloses a region that is not covered by the surrounding
+    /// faces, such as a hole in an otherwise closed surface.
+    Hole,
+    /// The boundary encloses the surrounding faces, such as the perimeter of
+    /// an open surface.
+    Outer,
+}
+
+/// Target of decimation.
+///
+/// See [`MeshGraph::decimate`].
+///
+/// [`MeshGraph::decimate`]: crate::graph::MeshGraph::decimate
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DecimationTarget<T> {
+    /// Edges are collapsed until the graph has no more than the given number
+    /// of faces.
+    FaceCount(usize),
+    /// Edges are collapsed until any further collapse would introduce more
+    /// than the given quadric error, which is the sum of squared distances to
+    /// the planes of the original faces around a vertex.
+    Error(T),
+}
+
+/// Policy for boundaries when decimating a graph.
+///
+/// See [`MeshGraph::decimate`].
+///
+/// [`MeshGraph::decimate`]: crate::graph::MeshGraph::decimate
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DecimationBoundary {
+    /// Boundary edges may be collapsed. Collapses that move vertices away
+    /// from boundaries are penalized, but boundaries may be simplified.
+    Collapse,
+    /// Boundary edges are never collapsed and vertices along boundaries are
+    /// never moved, so boundaries are preserved exactly.
+    Preserve,
+}
+
+/// Filter applied when smoothing a graph.
+///
+/// See [`MeshGraph::smooth_with`].
+///
+/// [`MeshGraph::smooth_with`]: crate::graph::MeshGraph::smooth_with
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SmoothingFilter<T> {
+    /// Vertices are translated toward the weighted mean of their adjacent
+    /// vertices by the given factor. Repeated Laplacian smoothing shrinks the
+    /// graph.
+    Laplacian(T),
+    /// Vertices are translated as with [`SmoothingFilter::Laplacian`] by the
+    /// positive factor $\lambda$ and then again by the negative factor $\mu$,
+    /// which inflates the graph and counteracts shrinkage. Typically,
+    /// $0<\lambda<-\mu$, such as $\lambda=0.5$ and $\mu=-0.53$.
+    ///
+    /// [`SmoothingFilter::Laplacian`]: crate::graph::SmoothingFilter::Laplacian
+    Taubin { lambda: T, mu: T },
+}
+
+/// Weighting of adjacent vertices when smoothing a graph.
+///
+/// See [`MeshGraph::smooth_with`].
+///
+/// [`MeshGraph::smooth_with`]: crate::graph::MeshGraph::smooth_with
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SmoothingWeight {
+    /// Adjacent vertices are weighted equally, so vertices are translated
+    /// toward their centroids. See [`VertexView::centroid`].
+    ///
+    /// [`VertexView::centroid`]: crate::graph::VertexView::centroid
+    Uniform,
+    /// Adjacent vertices are weighted by the cotangents of the angles
+    /// opposite the edges that connect them. This approximates the curvature
+    /// of a triangular surface and moves vertices along its normal rather
+    /// than redistributing them within the surface, which better preserves
+    /// thin features. Vertices that are adjacent to faces that are not
+    /// triangles are weighted uniformly.
+    Cotangent,
+}
+
+/// Policy for boundaries when smoothing a graph.
+///
+/// See [`MeshGraph::smooth_with`].
+///
+/// [`MeshGraph::smooth_with`]: crate::graph::MeshGraph::smooth_with
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SmoothingBoundary {
+    /// Vertices along boundaries are smoothed like any other vertex, which
+    /// pulls open boundaries inward.
+    Move,
+    /// Vertices along boundaries are never moved.
+    Pin,
+}
+
 /// [Half-edge graph][dcel] representation of a polygonal mesh.
 ///
 /// `MeshGraph`s form a polygonal mesh from four interconnected entities:
@@ -628,6 +780,125 @@ where
         MeshGraph::from(Core::default())
     }
 
+    /// Creates a `MeshGraph` from [raw buffers][`buffer`], repairing
+    /// non-manifold topology.
+    ///
+    /// Unlike [`FromRawBuffers`], this function tolerates input that cannot
+    /// otherwise be represented by a graph. Degenerate faces are dropped,
+    /// faces are detached from edges that are shared by more than two faces,
+    /// faces are reversed (or dropped) to agree on winding, and vertices
+    /// that join otherwise disjoint fans of faces (_bowties_) are duplicated.
+    /// Vertices that are not referenced by any remaining face are dropped.
+    /// This is useful for importing data from scans and other sources that
+    /// are not strictly manifold, such as PLY and STL files.
+    ///
+    /// Returns the graph and a report of each [`Repair`] that has been
+    /// applied, in which faces and vertices are identified by their indices
+    /// in the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Importing two triangles that only share a vertex:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::{MeshGraph, Repair};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// let (graph, repairs) = MeshGraph::<E2>::from_raw_buffers_with_repair(
+    ///     vec![Trigon::new(0u32, 1, 2), Trigon::new(0, 3, 4)],
+    ///     vec![
+    ///         (0.0, 0.0),
+    ///         (1.0, 0.0),
+    ///         (1.0, 1.0),
+    ///         (-1.0, 0.0),
+    ///         (-1.0, -1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(6, graph.vertex_count());
+    /// assert_eq!(vec![Repair::DuplicatedVertex { vertex: 0 }], repairs);
+    /// ```
+    ///
+    /// [`buffer`]: crate::buffer
+    /// [`FromRawBuffers`]: crate::buffer::FromRawBuffers
+    /// [`Repair`]: crate::graph::Repair
+    pub fn from_raw_buffers_with_repair<P, H, I, J>(
+        indices: I,
+        vertices: J,
+    ) -> Result<(Self, Vec<Repair>), GraphError>
+    where
+        P: IntoVertices + Polygonal,
+        P::Vertex: Integer + ToPrimitive + Unsigned,
+        G::Vertex: FromGeometry<H>,
+        I: IntoIterator<Item = P>,
+        J: IntoIterator<Item = H>,
+    {
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| vertex.into_geometry())
+            .collect::<Vec<_>>();
+        let faces = indices
+            .into_iter()
+            .map(|face| {
+                face.into_vertices()
+                    .into_iter()
+                    .map(|index| {
+                        <usize as NumCast>::from(index).ok_or(GraphError::TopologyNotFound)
+                    })
+                    .collect::<Result<SmallVec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        repair::from_faces_with_repair(vertices, faces)
+    }
+
+    /// Creates a `MeshGraph` from an iterator of polygons using the given
+    /// [`Indexer`], repairing non-manifold topology.
+    ///
+    /// See [`MeshGraph::from_raw_buffers_with_repair`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repaired topology cannot be inserted into the
+    /// graph.
+    ///
+    /// [`Indexer`]: crate::index::Indexer
+    /// [`MeshGraph::from_raw_buffers_with_repair`]: crate::graph::MeshGraph::from_raw_buffers_with_repair
+    pub fn from_indexer_with_repair<P, I, N>(
+        input: I,
+        indexer: N,
+    ) -> Result<(Self, Vec<Repair>), GraphError>
+    where
+        G::Vertex: FromGeometry<P::Vertex>,
+        P: Map<usize> + Polygonal,
+        P::Output: Grouping<Group = P::Output> + IntoVertices + Polygonal<Vertex = usize>,
+        Vec<P::Output>: IndexBuffer<P::Output, Index = usize>,
+        I: IntoIterator<Item = P>,
+        N: Indexer<P, P::Vertex>,
+    {
+        let (indices, vertices) = input.into_iter().index_vertices::<P::Output, _>(indexer);
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| vertex.into_geometry())
+            .collect::<Vec<_>>();
+        let faces = indices
+            .into_iter()
+            .map(|face| face.into_vertices().into_iter().collect())
+            .collect();
+        repair::from_faces_with_repair(vertices, faces)
+    }
+
     /// Gets the number of vertices in the graph.
     pub fn vertex_count(&self) -> usize {
         self.core.vertices.len()
@@ -804,12 +1075,152 @@ where
         Aabb::from_points(self.vertices().map(|vertex| *vertex.position()))
     }
 
+    /// Gets the mixed Voronoi area of each vertex in the graph.
+    ///
+    /// Vertices with degenerate geometry, such as vertices with no area, are
+    /// omitted. See [`VertexView::area`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any vertex is adjacent to a face that is not a
+    /// triangle.
+    ///
+    /// [`VertexView::area`]: crate::graph::VertexView::area
+    pub fn vertex_areas(&self) -> Result<HashMap<VertexKey, Scalar<VertexPosition<G>>>, GraphError>
+    where
+        G: VertexArea,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.vertices()
+            .flat_map(|vertex| match vertex.area() {
+                Ok(area) => Some(Ok((vertex.key(), area))),
+                Err(GraphError::Geometry) => None,
+                Err(error) => Some(Err(error)),
+            })
+            .collect()
+    }
+
+    /// Gets the Gaussian curvature of each vertex in the graph.
+    ///
+    /// Vertices with degenerate geometry, such as vertices with no area, are
+    /// omitted. See [`VertexView::gaussian_curvature`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any vertex is adjacent to a face that is not a
+    /// triangle.
+    ///
+    /// [`VertexView::gaussian_curvature`]: crate::graph::VertexView::gaussian_curvature
+    pub fn gaussian_curvatures(
+        &self,
+    ) -> Result<HashMap<VertexKey, Scalar<VertexPosition<G>>>, GraphError>
+    where
+        G: VertexGaussianCurvature,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.vertices()
+            .flat_map(|vertex| match vertex.gaussian_curvature() {
+                Ok(curvature) => Some(Ok((vertex.key(), curvature))),
+                Err(GraphError::Geometry) => None,
+                Err(error) => Some(Err(error)),
+            })
+            .collect()
+    }
+
+    /// Gets the mean curvature of each vertex in the graph.
+    ///
+    /// Vertices with degenerate geometry, such as vertices with no area, are
+    /// omitted. See [`VertexView::mean_curvature`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any vertex is adjacent to a face that is not a
+    /// triangle.
+    ///
+    /// [`VertexView::mean_curvature`]: crate::graph::VertexView::mean_curvature
+    pub fn mean_curvatures(
+        &self,
+    ) -> Result<HashMap<VertexKey, Scalar<VertexPosition<G>>>, GraphError>
+    where
+        G: VertexMeanCurvature,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.vertices()
+            .flat_map(|vertex| match vertex.mean_curvature() {
+                Ok(curvature) => Some(Ok((vertex.key(), curvature))),
+                Err(GraphError::Geometry) => None,
+                Err(error) => Some(Err(error)),
+            })
+            .collect()
+    }
+
+    /// Gets the principal curvatures and directions of each vertex in the
+    /// graph.
+    ///
+    /// Vertices with degenerate geometry, such as vertices with no area or
+    /// fewer than three adjacent vertices, are omitted. See
+    /// [`VertexView::principal_curvatures`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any vertex is adjacent to a face that is not a
+    /// triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<Point3<f64>> = UvSphere::new(32, 32)
+    ///     .polygons::<Position<E3>>()
+    ///     .triangulate()
+    ///     .collect();
+    /// // Both principal curvatures of a unit sphere are one.
+    /// for curvatures in graph.principal_curvatures().unwrap().values() {
+    ///     assert!((curvatures.maximum - 1.0).abs() < 0.25);
+    ///     assert!((curvatures.minimum - 1.0).abs() < 0.25);
+    /// }
+    /// ```
+    ///
+    /// [`VertexView::principal_curvatures`]: crate::graph::VertexView::principal_curvatures
+    pub fn principal_curvatures(
+        &self,
+    ) -> Result<HashMap<VertexKey, PrincipalCurvatures<VertexPosition<G>>>, GraphError>
+    where
+        G: VertexPrincipalCurvature,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.vertices()
+            .flat_map(|vertex| match vertex.principal_curvatures() {
+                Ok(curvatures) => Some(Ok((vertex.key(), curvatures))),
+                Err(GraphError::Geometry) => None,
+                Err(error) => Some(Err(error)),
+            })
+            .collect()
+    }
+
     // TODO: This triangulation does not consider geometry and exhibits some
     //       bad behavior in certain situations. Triangulation needs to be
     //       reworked and may need to expose a bit more complexity. A geometric
     //       triangulation algorithm would be a useful addition and could
     //       detect concave faces and provide more optimal splits. See comments
-    //       on `FaceView::triangulate`.
+    //       on `FaceView::triangulate` and see `try_triangulate` for a
+    //       geometric triangulation.
     /// Triangulates the graph, tessellating all faces into triangles.
     pub fn triangulate(&mut self) {
         // TODO: This implementation is a bit fragile and depends on the
@@ -849,75 +1260,1159 @@ where
         }
     }
 
-    /// Smooths the positions of vertices in the graph.
+    /// Triangulates the graph using its geometry, tessellating all faces into
+    /// triangles.
     ///
-    /// Each position is translated by its offset from its centroid scaled by
-    /// the given factor. The centroid of a vertex position is the mean of the
-    /// positions of its adjacent vertices. That is, given a factor $k$ and a
-    /// vertex with position $P$ and centroid $Q$, its position becomes
-    /// $P+k(Q-P)$.
-    pub fn smooth<T>(&mut self, factor: T)
+    /// Each face is triangulated via ear clipping in its best-fit plane. See
+    /// [`FaceView::try_triangulate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any face cannot be triangulated. Faces are
+    /// triangulated independently and any faces that have already been
+    /// triangulated when an error is encountered remain triangulated.
+    ///
+    /// [`FaceView::try_triangulate`]: crate::graph::FaceView::try_triangulate
+    pub fn try_triangulate(&mut self) -> Result<(), GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        let keys = self
+            .core
+            .faces
+            .iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        for key in keys {
+            self.face_mut(key).expect_consistent().try_triangulate()?;
+        }
+        Ok(())
+    }
+
+    /// Triangulates the graph via constrained Delaunay triangulations of its
+    /// faces.
+    ///
+    /// If a minimum angle (in radians) is given, then Steiner vertices are
+    /// inserted into the interior of faces to refine their triangulations. See
+    /// [`FaceView::triangulate_delaunay`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any face cannot be triangulated. Faces are
+    /// triangulated independently and any faces that have already been
+    /// triangulated when an error is encountered remain triangulated.
+    ///
+    /// [`FaceView::triangulate_delaunay`]: crate::graph::FaceView::triangulate_delaunay
+    pub fn triangulate_delaunay(
+        &mut self,
+        angle: Option<Scalar<VertexPosition<G>>>,
+    ) -> Result<(), GraphError>
     where
-        T: Into<Scalar<VertexPosition<G>>>,
-        G: VertexCentroid,
         G::Vertex: AsPositionMut,
         VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
     {
-        let factor = factor.into();
-        let mut positions = HashMap::with_capacity(self.vertex_count());
-        for vertex in self.vertices() {
-            let position = *vertex.position();
-            positions.insert(
-                vertex.key(),
-                position + ((vertex.centroid() - position) * factor),
-            );
+        let keys = self
+            .core
+            .faces
+            .iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        for key in keys {
+            self.face_mut(key)
+                .expect_consistent()
+                .triangulate_delaunay(angle)?;
         }
-        for mut vertex in self.vertex_orphans() {
-            *vertex.get_mut().as_position_mut() = positions.remove(&vertex.key()).unwrap();
+        Ok(())
+    }
+
+    /// Subdivides the graph via Catmull–Clark subdivision.
+    ///
+    /// Each level of subdivision inserts a vertex at the centroid of each face
+    /// and a vertex for each edge and then replaces each face with a
+    /// quadrilateral for each of its vertices. The positions of existing
+    /// vertices are updated such that the graph converges to a smooth surface.
+    ///
+    /// Open graphs use boundary rules: vertices inserted for boundary edges
+    /// are placed at their midpoints and vertices along boundaries are only
+    /// influenced by their neighbors along the boundary. Vertices at which
+    /// more than two boundary edges meet are not moved.
+    ///
+    /// Inserted vertices copy the data of an adjacent vertex and faces copy
+    /// the data of the face from which they are subdivided. Arc and edge data
+    /// are not preserved and edges that are not adjacent to any face are
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// Subdividing a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::index::HashIndexer;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::Tetragon;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let (indices, vertices) = Cube::new()
+    ///     .polygons::<Position<E3>>()
+    ///     .index_vertices::<Tetragon<usize>, _>(HashIndexer::default());
+    /// let mut graph = MeshGraph::<E3>::from_raw_buffers(indices, vertices).unwrap();
+    /// graph.subdivide_catmull_clark(2);
+    ///
+    /// assert_eq!(96, graph.face_count());
+    /// ```
+    pub fn subdivide_catmull_clark(&mut self, levels: usize)
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        for _ in 0..levels {
+            *self = subdivision::catmull_clark(self);
         }
     }
 
-    /// Splits the graph along a path.
+    /// Subdivides a triangular graph via Loop subdivision.
     ///
-    /// Splitting a graph creates boundaries along the given path and copies any
-    /// necessary vertex, arc, and edge data.
+    /// Each level of subdivision inserts a vertex for each edge and replaces
+    /// each triangle with four triangles. Vertices inserted for edges are
+    /// placed using the Loop edge mask and the positions of existing vertices
+    /// are updated using weights that depend on their valence.
     ///
-    /// If the path bisects the graph, then splitting will result in disjointed
-    /// sub-graphs.
+    /// Boundary edges are treated as creases: vertices inserted for boundary
+    /// edges are placed at their midpoints and vertices along boundaries are
+    /// only influenced by their neighbors along the boundary. Vertices at which
+    /// more than two boundary edges meet are not moved.
+    ///
+    /// Inserted vertices copy the data of an adjacent vertex and faces copy
+    /// the data of the face from which they are subdivided. Arc and edge data
+    /// are not preserved and edges that are not adjacent to any face are
+    /// removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not triangular. The graph is not
+    /// modified if an error occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(3, 2).polygons::<Position<E3>>().collect();
+    /// graph.subdivide_loop(2).unwrap();
+    ///
+    /// assert_eq!(96, graph.face_count());
+    /// ```
+    pub fn subdivide_loop(&mut self, levels: usize) -> Result<(), GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        for _ in 0..levels {
+            *self = subdivision::loop_scheme(self)?;
+        }
+        Ok(())
+    }
+
+    /// Subdivides the graph via Doo–Sabin subdivision.
+    ///
+    /// Doo–Sabin subdivision is a dual scheme that cuts the corners of faces.
+    /// Each level of subdivision inserts a vertex for each corner of each face
+    /// (that is, for each arc) at the centroid of the corner's vertex, the
+    /// centroid of the face, and the midpoints of the edges of the face that
+    /// are incident to the vertex. Faces are then inserted for each face, each
+    /// interior edge, and each interior vertex. The graph converges to a
+    /// smooth surface that does not interpolate any of its original vertices.
+    ///
+    /// No faces are inserted for boundary edges and vertices, so open graphs
+    /// shrink away from their boundaries.
+    ///
+    /// Inserted vertices copy the data of the vertex at their corner and faces
+    /// inserted for faces copy the data of that face. Arc and edge data are not
+    /// preserved and faces inserted for edges and vertices use default data.
+    ///
+    /// # Examples
+    ///
+    /// Subdividing a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::index::HashIndexer;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::Tetragon;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let (indices, vertices) = Cube::new()
+    ///     .polygons::<Position<E3>>()
+    ///     .index_vertices::<Tetragon<usize>, _>(HashIndexer::default());
+    /// let mut graph = MeshGraph::<E3>::from_raw_buffers(indices, vertices).unwrap();
+    /// graph.subdivide_doo_sabin(1);
+    ///
+    /// // A face is inserted for each face, edge, and vertex.
+    /// assert_eq!(26, graph.face_count());
+    /// ```
+    pub fn subdivide_doo_sabin(&mut self, levels: usize)
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        for _ in 0..levels {
+            *self = subdivision::doo_sabin(self);
+        }
+    }
+
+    /// Subdivides a triangular graph via Kobbelt's $\sqrt{3}$ subdivision.
+    ///
+    /// Each level of subdivision pokes each face at its centroid (see
+    /// [`FaceView::poke_at_centroid`]) and then flips each of the original
+    /// interior edges (see [`ArcView::flip`]). The positions of interior
+    /// vertices are relaxed toward the centroid of their adjacent vertices
+    /// using weights that depend on their valence. Each level triples the
+    /// number of faces, so refinement is slower than with Loop subdivision.
+    ///
+    /// Boundary edges are never flipped and vertices along boundaries are not
+    /// moved.
+    ///
+    /// Unlike other subdivision schemes, $\sqrt{3}$ subdivision modifies the
+    /// graph in place and so preserves keys and data of existing entities
+    /// where possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not triangular or if an edge cannot be
+    /// flipped, such as when two triangles share more than one edge. The graph
+    /// is not modified if it is not triangular, but it may be partially
+    /// subdivided if an edge cannot be flipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(3, 2).polygons::<Position<E3>>().collect();
+    /// graph.subdivide_sqrt3(2).unwrap();
+    ///
+    /// assert_eq!(54, graph.face_count());
+    /// ```
+    ///
+    /// [`ArcView::flip`]: crate::graph::ArcView::flip
+    /// [`FaceView::poke_at_centroid`]: crate::graph::FaceView::poke_at_centroid
+    pub fn subdivide_sqrt3(&mut self, levels: usize) -> Result<(), GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Scalar<VertexPosition<G>>: NumCast,
+    {
+        for _ in 0..levels {
+            subdivision::sqrt3(self)?;
+        }
+        Ok(())
+    }
+
+    /// Constructs the dual of the graph, placing vertices at the centroids of
+    /// faces.
+    ///
+    /// See [`MeshGraph::to_dual_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dual cannot be constructed.
+    ///
+    /// # Examples
+    ///
+    /// Constructing a hexagonal tiling of a sphere:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{DualBoundary, MeshGraph};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(8, 8).polygons::<Position<E3>>().collect();
+    /// graph.triangulate();
+    /// let dual = graph.to_dual(DualBoundary::Skip).unwrap();
+    ///
+    /// assert_eq!(graph.face_count(), dual.vertex_count());
+    /// assert_eq!(graph.vertex_count(), dual.face_count());
+    /// ```
+    ///
+    /// [`MeshGraph::to_dual_with`]: crate::graph::MeshGraph::to_dual_with
+    pub fn to_dual(&self, boundary: DualBoundary) -> Result<Self, GraphError>
+    where
+        G: FaceCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.to_dual_with(boundary, |face| face.centroid())
+    }
+
+    /// Constructs the dual of the graph.
+    ///
+    /// A face is inserted for each vertex and a vertex is inserted for each
+    /// face. The vertices of each inserted face are ordered by the faces
+    /// about its vertex such that the dual is consistently oriented with the
+    /// graph. The given function computes the position of the vertex inserted
+    /// for each face. All other vertex data is copied from a vertex of that
+    /// face. Inserted faces use default data and arc and edge data are not
+    /// preserved.
+    ///
+    /// The dual of a closed graph is closed. Boundary vertices are handled
+    /// according to the given [`DualBoundary`] policy. Vertices that are
+    /// shared by more than one boundary are always skipped. Faces that are
+    /// only adjacent to skipped vertices have no vertex in the dual.
+    ///
+    /// See also [`conway::dual_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dual cannot be constructed, such as when two
+    /// faces share more than one edge.
+    ///
+    /// [`conway::dual_with`]: crate::graph::conway::dual_with
+    /// [`DualBoundary`]: crate::graph::DualBoundary
+    pub fn to_dual_with<F>(&self, boundary: DualBoundary, f: F) -> Result<Self, GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        F: FnMut(FaceView<&Self>) -> VertexPosition<G>,
+    {
+        match boundary {
+            DualBoundary::Skip => conway::dual_with(self, f),
+            DualBoundary::Close => conway::dual_with_boundary(
+                self,
+                f,
+                Some(|edge: EdgeView<&Self>| {
+                    let arc = edge.arc();
+                    subdivision::centroid([
+                        *arc.source_vertex().position(),
+                        *arc.destination_vertex().position(),
+                    ])
+                }),
+            ),
+        }
+    }
+
+    /// Fills holes in the graph.
+    ///
+    /// Each boundary of the graph (see [`MeshGraph::boundaries`]) with an
+    /// arity that does not exceed the given arity is filled using the given
+    /// strategy (see [`Ring::fill`]). Outer boundaries of open surfaces are
+    /// not filled (see [`Ring::boundary_kind`]), but boundaries that cannot be
+    /// classified are. This is useful for closing small holes in meshes from
+    /// scans, for example.
+    ///
+    /// Returns the number of holes that have been filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a hole cannot be filled. Holes are filled in an
+    /// arbitrary order, so the graph may be partially filled when an error
+    /// occurs. In that case, the holes that have already been filled remain
+    /// filled and their number is not reported.
+    ///
+    /// # Examples
+    ///
+    /// Closing a UV-sphere that is missing faces:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{HoleFill, MeshGraph};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(8, 8)
+    ///     .polygons::<Position<E3>>()
+    ///     .skip(1)
+    ///     .collect();
+    /// let count = graph.fill_holes(8, HoleFill::Fair).unwrap();
+    ///
+    /// assert_eq!(1, count);
+    /// assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+    /// ```
+    ///
+    /// [`MeshGraph::boundaries`]: crate::graph::MeshGraph::boundaries
+    /// [`Ring::boundary_kind`]: crate::graph::Ring::boundary_kind
+    /// [`Ring::fill`]: crate::graph::Ring::fill
+    pub fn fill_holes(&mut self, arity: usize, fill: HoleFill) -> Result<usize, GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        let holes = self
+            .boundaries()
+            .filter(|ring| ring.arity() <= arity && ring.boundary_kind() != Ok(BoundaryKind::Outer))
+            .map(|ring| ring.arc().key())
+            .collect::<Vec<_>>();
+        for key in holes.iter().cloned() {
+            self.arc_mut(key)
+                .expect_consistent()
+                .into_ring()
+                .fill(fill)?;
+        }
+        Ok(holes.len())
+    }
+
+    /// Smooths the positions of vertices in the graph.
+    ///
+    /// Each position is translated by its offset from its centroid scaled by
+    /// the given factor. The centroid of a vertex position is the mean of the
+    /// positions of its adjacent vertices. That is, given a factor $k$ and a
+    /// vertex with position $P$ and centroid $Q$, its position becomes
+    /// $P+k(Q-P)$.
+    ///
+    /// See [`MeshGraph::smooth_with`] for other filters and weightings and to
+    /// pin vertices.
+    ///
+    /// [`MeshGraph::smooth_with`]: crate::graph::MeshGraph::smooth_with
+    pub fn smooth<T>(&mut self, factor: T)
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: VertexCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let factor = factor.into();
+        let mut positions = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let position = *vertex.position();
+            positions.insert(
+                vertex.key(),
+                position + ((vertex.centroid() - position) * factor),
+            );
+        }
+        for mut vertex in self.vertex_orphans() {
+            *vertex.get_mut().as_position_mut() = positions.remove(&vertex.key()).unwrap();
+        }
+    }
+
+    /// Smooths the positions of vertices in the graph using the given filter
+    /// and weighting.
+    ///
+    /// Vertices along boundaries are pinned if `boundary` is
+    /// [`SmoothingBoundary::Pin`] and the vertices with the given keys are
+    /// always pinned. Pinned vertices are never moved, but are considered when
+    /// smoothing their neighbors. All offsets of a pass are computed before
+    /// any positions are written, so the result does not depend on the order
+    /// of vertices.
+    ///
+    /// # Examples
+    ///
+    /// Smoothing a noisy sphere without shrinking it:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{MeshGraph, SmoothingBoundary, SmoothingFilter, SmoothingWeight};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(16, 16)
+    ///     .polygons::<Position<E3>>()
+    ///     .triangulate()
+    ///     .collect();
+    /// for _ in 0..8 {
+    ///     graph.smooth_with(
+    ///         SmoothingFilter::Taubin {
+    ///             lambda: 0.5.into(),
+    ///             mu: (-0.53).into(),
+    ///         },
+    ///         SmoothingWeight::Cotangent,
+    ///         SmoothingBoundary::Pin,
+    ///         None,
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`SmoothingBoundary::Pin`]: crate::graph::SmoothingBoundary::Pin
+    pub fn smooth_with<I>(
+        &mut self,
+        filter: SmoothingFilter<Scalar<VertexPosition<G>>>,
+        weight: SmoothingWeight,
+        boundary: SmoothingBoundary,
+        pinned: I,
+    ) where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+        I: IntoIterator<Item = VertexKey>,
+    {
+        smoothing::smooth_with(self, filter, weight, boundary, pinned)
+    }
+
+    /// Splits the graph along a path.
+    ///
+    /// Splitting a graph creates boundaries along the given path and copies any
+    /// necessary vertex, arc, and edge data. Vertices along the path are copied
+    /// for each fan of faces that is separated by the path, so the back and
+    /// front of an open path are only copied if they are on a boundary.
+    ///
+    /// If the path bisects the graph, then splitting will result in disjointed
+    /// sub-graphs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path cannot be cut. Edges are identified by
+    /// their vertices, so a path that cuts an edge between two vertices that
+    /// are not copied (such as a single interior arc) cannot be split.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// // Create a graph from two triangles.
+    /// let mut graph = MeshGraph::<E2>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(2, 1, 3)],
+    ///     vec![(-1.0, 0.0), (0.0, -1.0), (0.0, 1.0), (1.0, 0.0)],
+    /// )
+    /// .unwrap();
+    ///
+    /// // Find the shared edge that bisects the triangles and then construct a path
+    /// // along the edge and split the graph.
+    /// let key = graph
+    ///     .edges()
+    ///     .find(|edge| !edge.is_boundary_edge())
+    ///     .map(|edge| edge.into_arc().key())
+    ///     .unwrap();
+    /// let path = graph.arc_mut(key).unwrap().into_path();
+    /// MeshGraph::split_at_path(path).unwrap();
+    ///
+    /// assert_eq!(2, graph.disjoint_subgraph_vertices().count());
+    /// ```
+    pub fn split_at_path(path: Path<&mut Self>) -> Result<(), GraphError> {
+        let cache = PathSplitCache::from_path(path.to_ref())?;
+        let storage = path.into_storage();
+        Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| mutation::path::split(mutation, cache))
+            .map(|_| ())
+            .map_err(|(_, error)| error)
+    }
+
+    /// Reverses the winding of all faces in the graph.
+    ///
+    /// The rings of all faces and boundaries are reversed, which negates the
+    /// normals of faces. Keys are unchanged, but arc data is associated with
+    /// the direction of arcs, so the rings of faces are formed from what were
+    /// their opposite arcs. See [`FaceView::reverse`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// let normal = graph.face(key).unwrap().normal().unwrap();
+    ///
+    /// graph.reverse();
+    /// assert_eq!(-normal, graph.face(key).unwrap().normal().unwrap());
+    /// ```
+    ///
+    /// [`FaceView::reverse`]: crate::graph::FaceView::reverse
+    pub fn reverse(&mut self) {
+        let keys = self
+            .core
+            .faces
+            .iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        // This should never fail here.
+        let cache = FaceReverseCache::from_storage(&*self, keys).expect_consistent();
+        Mutation::take(self)
+            .bypass_or_commit_with(|mutation| mutation::face::reverse(mutation, cache))
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+    }
+
+    /// Orients the faces of the graph such that closed sub-graphs face
+    /// outward.
+    ///
+    /// Adjacent faces in a graph share opposite arcs, so their winding always
+    /// agrees and each disjoint sub-graph is consistently wound (inconsistent
+    /// winding is resolved or rejected when a graph is constructed; see
+    /// [`MeshGraph::from_raw_buffers_with_repair`]). Orientation chooses the
+    /// winding of each disjoint sub-graph as a whole: a closed sub-graph is
+    /// reversed if the signed volume that it encloses is negative, so that the
+    /// normals of its faces point outward. Sub-graphs with boundaries do not
+    /// enclose a volume and are not modified.
+    ///
+    /// Returns the number of disjoint sub-graphs that have been reversed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// graph.reverse();
+    ///
+    /// assert_eq!(1, graph.orient());
+    /// assert_eq!(0, graph.orient());
+    /// ```
+    ///
+    /// [`MeshGraph::from_raw_buffers_with_repair`]: crate::graph::MeshGraph::from_raw_buffers_with_repair
+    pub fn orient(&mut self) -> usize
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+    {
+        let mut count = 0;
+        let mut keys = vec![];
+        for vertex in self.disjoint_subgraph_vertices() {
+            let vertices = vertex.traverse_by_depth().collect::<Vec<_>>();
+            if vertices
+                .iter()
+                .any(|vertex| vertex.outgoing_arcs().any(|arc| arc.is_boundary_arc()))
+            {
+                continue;
+            }
+            let faces = vertices
+                .iter()
+                .flat_map(|vertex| vertex.adjacent_faces())
+                .map(|face| face.key())
+                .collect::<HashSet<_>>();
+            // Sum the signed volumes of tetrahedra formed by an arbitrary
+            // origin and a fan triangulation of each face.
+            let origin = *vertex.position();
+            let volume = faces
+                .iter()
+                .map(|key| {
+                    let positions = self
+                        .face(*key)
+                        .expect_consistent()
+                        .adjacent_vertices()
+                        .map(|vertex| *vertex.position() - origin)
+                        .collect::<Vec<_>>();
+                    positions[1..].windows(2).fold(
+                        Zero::zero(),
+                        |volume: Scalar<VertexPosition<G>>, edge| {
+                            volume + positions[0].dot(edge[0].cross(edge[1]))
+                        },
+                    )
+                })
+                .fold(Zero::zero(), |sum: Scalar<VertexPosition<G>>, volume| {
+                    sum + volume
+                });
+            if volume.is_negative() {
+                count += 1;
+                keys.extend(faces);
+            }
+        }
+        // This should never fail here, because the faces of closed disjoint
+        // sub-graphs are reversed together.
+        let cache = FaceReverseCache::from_storage(&*self, keys).expect_consistent();
+        Mutation::take(self)
+            .bypass_or_commit_with(|mutation| mutation::face::reverse(mutation, cache))
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        count
+    }
+
+    /// Merges adjacent coplanar faces.
+    ///
+    /// Adjacent faces are repeatedly merged if the angle between their normals
+    /// is within the given tolerance (in radians). The merged faces take the
+    /// data of one of their constituent faces. Afterward, any vertices that
+    /// are connected to exactly two collinear edges are removed. This is useful
+    /// for recovering polygons from triangulated meshes. See
+    /// [`MeshGraph::merge_coplanar_faces_with`].
+    ///
+    /// Returns the number of faces that have been removed.
+    ///
+    /// # Examples
+    ///
+    /// Recovering the quadrilaterals of a triangulated cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new()
+    ///     .polygons::<Position<E3>>()
+    ///     .triangulate()
+    ///     .collect();
+    ///
+    /// assert_eq!(6, graph.merge_coplanar_faces(1e-3.into()));
+    /// assert_eq!(6, graph.face_count());
+    /// ```
+    ///
+    /// [`MeshGraph::merge_coplanar_faces_with`]: crate::graph::MeshGraph::merge_coplanar_faces_with
+    pub fn merge_coplanar_faces(&mut self, angle: Scalar<VertexPosition<G>>) -> usize
+    where
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        self.merge_coplanar_faces_with(angle, |face, _| face.clone())
+    }
+
+    /// Merges adjacent coplanar faces using the given function to combine
+    /// their data.
+    ///
+    /// Adjacent faces are repeatedly merged if the angle between their normals
+    /// is within the given tolerance (in radians). The data of merged faces is
+    /// provided by the given function, which accepts the data of the faces
+    /// being merged. Afterward, any vertices that are connected to exactly two
+    /// collinear edges are removed.
+    ///
+    /// Faces are only merged if they share a contiguous path of edges and no
+    /// other vertices, such that the merged face is a simple polygon. Vertices
+    /// along the interior of such a path are removed. Merged faces need not be
+    /// convex, so they are compared using the normals of their constituent
+    /// faces.
+    ///
+    /// Returns the number of faces that have been removed.
+    pub fn merge_coplanar_faces_with<F>(&mut self, angle: Scalar<VertexPosition<G>>, f: F) -> usize
+    where
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+        F: FnMut(&G::Face, &G::Face) -> G::Face,
+    {
+        merging::merge_coplanar_faces_with(self, angle, f)
+    }
+
+    /// Decimates the graph by collapsing edges in order of their quadric
+    /// error.
+    ///
+    /// Each vertex accumulates the planes of its adjacent faces as a quadric
+    /// (see Garland and Heckbert, _Surface Simplification Using Quadric Error
+    /// Metrics_). Edges are collapsed in order of the error of the combined
+    /// quadrics of their vertices and the merged vertex is placed at the
+    /// position that minimizes this error. Collapses continue until the given
+    /// target is reached or no more edges can be collapsed. Collapses that
+    /// would flip the normal of a face or create non-manifold topology are
+    /// rejected. See [`ArcView::collapse_with`].
+    ///
+    /// The merged vertex takes the data of the destination vertex of the
+    /// collapsed arc. See [`MeshGraph::decimate_with`] to interpolate vertex
+    /// data.
+    ///
+    /// Returns the number of faces that have been removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{DecimationBoundary, DecimationTarget, MeshGraph};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(16, 16)
+    ///     .polygons::<Position<E3>>()
+    ///     .triangulate()
+    ///     .collect();
+    /// graph.decimate(
+    ///     DecimationTarget::FaceCount(100),
+    ///     DecimationBoundary::Preserve,
+    /// );
+    ///
+    /// assert!(graph.face_count() <= 100);
+    /// ```
+    ///
+    /// [`ArcView::collapse_with`]: crate::graph::ArcView::collapse_with
+    /// [`MeshGraph::decimate_with`]: crate::graph::MeshGraph::decimate_with
+    pub fn decimate(
+        &mut self,
+        target: DecimationTarget<Scalar<VertexPosition<G>>>,
+        boundary: DecimationBoundary,
+    ) -> usize
+    where
+        G: FaceNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+        Scalar<VertexPosition<G>>: NumCast,
+    {
+        self.decimate_with(target, boundary, |_, destination, _| destination.clone())
+    }
+
+    /// Decimates the graph by collapsing edges in order of their quadric
+    /// error using the given function to merge vertex data.
+    ///
+    /// The given function accepts the data of the source and destination
+    /// vertices of a collapsed arc and the parameter $t\in[0,1]$ of the
+    /// position of the merged vertex projected onto the arc, such that vertex
+    /// data like colors and texture coordinates can be interpolated. The
+    /// position of the returned data is replaced by the position that
+    /// minimizes the quadric error. See [`MeshGraph::decimate`].
+    ///
+    /// Returns the number of faces that have been removed.
+    ///
+    /// # Examples
+    ///
+    /// Interpolating the colors of vertices:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::geometry::{AsPosition, AsPositionMut};
+    /// use plexus::graph::{DecimationBoundary, DecimationTarget, GraphData, MeshGraph};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// #[derive(Clone, Copy, Eq, Hash, PartialEq)]
+    /// pub struct Vertex {
+    ///     pub position: E3,
+    ///     pub color: Vector3<R64>,
+    /// }
+    ///
+    /// impl GraphData for Vertex {
+    ///     type Vertex = Self;
+    ///     type Arc = ();
+    ///     type Edge = ();
+    ///     type Face = ();
+    /// }
+    ///
+    /// impl AsPosition for Vertex {
+    ///     type Position = E3;
+    ///
+    ///     fn as_position(&self) -> &Self::Position {
+    ///         &self.position
+    ///     }
+    /// }
+    ///
+    /// impl AsPositionMut for Vertex {
+    ///     fn as_position_mut(&mut self) -> &mut Self::Position {
+    ///         &mut self.position
+    ///     }
+    /// }
+    ///
+    /// let mut graph: MeshGraph<Vertex> = UvSphere::new(16, 16)
+    ///     .polygons::<Position<E3>>()
+    ///     .triangulate()
+    ///     .map_vertices(|position| Vertex {
+    ///         position,
+    ///         color: Vector3::new(1.0.into(), position.z, 0.0.into()),
+    ///     })
+    ///     .collect();
+    /// graph.decimate_with(
+    ///     DecimationTarget::FaceCount(100),
+    ///     DecimationBoundary::Preserve,
+    ///     |source, destination, t| Vertex {
+    ///         position: destination.position,
+    ///         color: source.color + ((destination.color - source.color) * t),
+    ///     },
+    /// );
+    /// ```
+    ///
+    /// [`MeshGraph::decimate`]: crate::graph::MeshGraph::decimate
+    pub fn decimate_with<F>(
+        &mut self,
+        target: DecimationTarget<Scalar<VertexPosition<G>>>,
+        boundary: DecimationBoundary,
+        f: F,
+    ) -> usize
+    where
+        G: FaceNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+        Scalar<VertexPosition<G>>: NumCast,
+        F: FnMut(&G::Vertex, &G::Vertex, Scalar<VertexPosition<G>>) -> G::Vertex,
+    {
+        decimation::decimate_with(self, target, boundary, f)
+    }
+
+    /// Remeshes the graph such that its faces are triangles with edges that
+    /// approach the given length.
+    ///
+    /// The graph is first triangulated. Each iteration then splits edges that
+    /// are longer than $\frac{4}{3}$ of the target length at their midpoints,
+    /// collapses edges that are shorter than $\frac{4}{5}$ of the target
+    /// length, flips edges to bring the valence of vertices toward six (or
+    /// four along boundaries), and relaxes vertices toward the centroid of
+    /// their neighbors within their tangent planes. Relaxed vertices are
+    /// projected back onto the original surface.
+    ///
+    /// Boundary edges and feature edges, which join faces with normals that
+    /// differ by more than 45 degrees, are preserved: they may be split, but
+    /// their vertices are never moved or collapsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the target length is not positive and finite or if
+    /// the graph cannot be triangulated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+    /// graph.remesh_isotropic(0.25.into(), 4).unwrap();
+    ///
+    /// assert!(graph.faces().all(|face| face.arity() == 3));
+    /// ```
+    pub fn remesh_isotropic(
+        &mut self,
+        target_length: Scalar<VertexPosition<G>>,
+        iterations: usize,
+    ) -> Result<(), GraphError>
+    where
+        G: EdgeMidpoint + FaceNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+        Scalar<VertexPosition<G>>: NumCast,
+    {
+        remeshing::remesh_isotropic(self, target_length, iterations)
+    }
+
+    /// Validates the topology of the graph.
+    ///
+    /// Examines every vertex, arc, edge, and face in the graph and reports
+    /// each [`Defect`] that is found along with the keys of the offending
+    /// entities. Unlike operations that fail with a [`GraphError`], this
+    /// reports all defects at once. A graph that has only been manipulated via
+    /// its APIs has no defects, so this is most useful for debugging
+    /// operations built on the mutation API and for gating imported data.
+    ///
+    /// See [`MeshGraph::validate_with_geometry`] to also report faces with
+    /// zero area.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(8, 8).polygons::<Position<E3>>().collect();
+    ///
+    /// assert!(graph.validate().is_empty());
+    /// ```
+    ///
+    /// [`Defect`]: crate::graph::Defect
+    /// [`GraphError`]: crate::graph::GraphError
+    /// [`MeshGraph::validate_with_geometry`]: crate::graph::MeshGraph::validate_with_geometry
+    pub fn validate(&self) -> Vec<Defect> {
+        validation::validate_with(self, |_| false)
+    }
+
+    /// Validates the topology and geometry of the graph.
+    ///
+    /// In addition to the defects reported by [`MeshGraph::validate`], faces
+    /// with zero area are reported. A face has zero area if the positions of
+    /// its vertices are coincident or collinear.
+    ///
+    /// [`MeshGraph::validate`]: crate::graph::MeshGraph::validate
+    pub fn validate_with_geometry(&self) -> Vec<Defect>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        validation::validate_with(self, |keys| {
+            keys.iter()
+                .map(|key| {
+                    self.core
+                        .vertices
+                        .get(key)
+                        .map(|vertex| *vertex.data.as_position())
+                })
+                .collect::<Option<Vec<_>>>()
+                .map_or(false, |points| {
+                    PlaneProjection::from_points(&points).is_none()
+                })
+        })
+    }
+
+    /// Gets an iterator of rings over the boundaries of the graph.
+    ///
+    /// A _boundary_ is a closed loop of boundary arcs, which have no
+    /// associated face. Each boundary is visited exactly once and its arcs are
+    /// ordered along the loop. See [`Ring::length`] and
+    /// [`Ring::boundary_kind`].
     ///
     /// # Examples
     ///
-    /// ```rust,no_run
+    /// Finding the holes in a UV-sphere that is missing faces:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
     /// # extern crate nalgebra;
     /// # extern crate plexus;
     /// #
-    /// use nalgebra::Point2;
-    /// use plexus::graph::MeshGraph;
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{BoundaryKind, MeshGraph};
     /// use plexus::prelude::*;
-    /// use plexus::primitive::Trigon;
-    ///
-    /// type E2 = Point2<f64>;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
     ///
-    /// // Create a graph from two triangles.
-    /// let mut graph = MeshGraph::<E2>::from_raw_buffers(
-    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(2, 1, 3)],
-    ///     vec![(-1.0, 0.0), (0.0, -1.0), (0.0, 1.0), (1.0, 0.0)],
-    /// )
-    /// .unwrap();
+    /// type E3 = Point3<R64>;
     ///
-    /// // Find the shared edge that bisects the triangles and then construct a path
-    /// // along the edge and split the graph.
-    /// let key = graph
-    ///     .edges()
-    ///     .find(|edge| !edge.is_boundary_edge())
-    ///     .map(|edge| edge.into_arc().key())
-    ///     .unwrap();
-    /// let mut path = graph.arc_mut(key).unwrap().into_path();
-    /// MeshGraph::split_at_path(path).unwrap();
+    /// let graph: MeshGraph<E3> = UvSphere::new(8, 8)
+    ///     .polygons::<Position<E3>>()
+    ///     .skip(1)
+    ///     .collect();
+    /// let holes = graph
+    ///     .boundaries()
+    ///     .filter(|ring| ring.boundary_kind() == Ok(BoundaryKind::Hole))
+    ///     .count();
+    ///
+    /// assert_eq!(1, holes);
     /// ```
-    pub fn split_at_path(path: Path<&mut Self>) -> Result<(), GraphError> {
-        let _ = path;
-        unimplemented!()
+    ///
+    /// [`Ring::boundary_kind`]: crate::graph::Ring::boundary_kind
+    /// [`Ring::length`]: crate::graph::Ring::length
+    pub fn boundaries(&self) -> impl ExactSizeIterator<Item = Ring<&Self>> {
+        let mut visited = HashSet::new();
+        let mut rings = vec![];
+        for arc in self.arcs().filter(|arc| arc.is_boundary_arc()) {
+            if visited.insert(arc.key()) {
+                let ring = arc.into_ring();
+                visited.extend(ring.arcs().map(|arc| arc.key()));
+                rings.push(ring);
+            }
+        }
+        rings.into_iter()
     }
 
     /// Gets an iterator over a vertex within each disjoint sub-graph.
@@ -976,8 +2471,130 @@ where
     }
 
     /// Moves disjoint sub-graphs into separate graphs.
-    pub fn into_disjoint_subgraphs(self) -> Vec<Self> {
-        unimplemented!()
+    ///
+    /// All vertex, arc, edge, and face data is moved into the sub-graphs.
+    /// Returns the sub-graphs and a map from the keys of entities in the graph
+    /// to the index of their sub-graph and their key within that sub-graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// // Create a graph from two disjoint triangles.
+    /// let graph = MeshGraph::<E2>::from_raw_buffers(
+    ///     vec![Trigon::new(0u32, 1, 2), Trigon::new(3, 4, 5)],
+    ///     vec![
+    ///         (-2.0, 0.0),
+    ///         (-1.0, 0.0),
+    ///         (-1.0, 1.0),
+    ///         (1.0, 0.0),
+    ///         (2.0, 0.0),
+    ///         (1.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// let (graphs, _) = graph.into_disjoint_subgraphs();
+    /// assert_eq!(2, graphs.len());
+    /// ```
+    pub fn into_disjoint_subgraphs(self) -> (Vec<Self>, HashMap<GraphKey, (usize, GraphKey)>) {
+        let mut partition = HashMap::with_capacity(self.vertex_count());
+        for (index, vertex) in self.disjoint_subgraph_vertices().enumerate() {
+            for key in vertex.traverse_by_depth().keys() {
+                partition.insert(key, index);
+            }
+        }
+        let n = partition
+            .values()
+            .cloned()
+            .max()
+            .map_or(0, |index| index + 1);
+        let mut keys = HashMap::with_capacity(
+            self.vertex_count() + self.arc_count() + self.edge_count() + self.face_count(),
+        );
+        let mut storages = (0..n)
+            .map(|_| {
+                (
+                    <<Vertex<G> as Entity>::Storage as Default>::default(),
+                    <<Arc<G> as Entity>::Storage as Default>::default(),
+                    <<Edge<G> as Entity>::Storage as Default>::default(),
+                    <<Face<G> as Entity>::Storage as Default>::default(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let (mut vertices, mut arcs, mut edges, mut faces) = self.core.unfuse();
+        // Move vertices into their sub-graphs. Arcs are keyed by their vertices
+        // and so their keys can be determined once all vertices are moved.
+        let mut vertex_keys = HashMap::with_capacity(partition.len());
+        for (a, index) in partition.iter().map(|(a, index)| (*a, *index)) {
+            let vertex = vertices.remove(&a).expect_consistent();
+            let b = storages[index].0.insert(vertex);
+            vertex_keys.insert(a, b);
+            keys.insert(a.into(), (index, b.into()));
+        }
+        let arc_key = |ab: ArcKey| -> ArcKey {
+            let (a, b) = ab.into();
+            (vertex_keys[&a], vertex_keys[&b]).into()
+        };
+        let index_of = |ab: ArcKey| {
+            let (a, _) = ab.into();
+            partition[&a]
+        };
+        let mut edge_keys = HashMap::with_capacity(edges.len());
+        for ab_ba in edges.iter().map(|(key, _)| key).collect::<Vec<_>>() {
+            let mut edge = edges.remove(&ab_ba).expect_consistent();
+            let index = index_of(edge.arc);
+            edge.arc = arc_key(edge.arc);
+            let key = storages[index].2.insert(edge);
+            edge_keys.insert(ab_ba, key);
+            keys.insert(ab_ba.into(), (index, key.into()));
+        }
+        let mut face_keys = HashMap::with_capacity(faces.len());
+        for abc in faces.iter().map(|(key, _)| key).collect::<Vec<_>>() {
+            let mut face = faces.remove(&abc).expect_consistent();
+            let index = index_of(face.arc);
+            face.arc = arc_key(face.arc);
+            let key = storages[index].3.insert(face);
+            face_keys.insert(abc, key);
+            keys.insert(abc.into(), (index, key.into()));
+        }
+        for ab in arcs.iter().map(|(key, _)| key).collect::<Vec<_>>() {
+            let mut arc = arcs.remove(&ab).expect_consistent();
+            let index = index_of(ab);
+            arc.next = arc.next.map(arc_key);
+            arc.previous = arc.previous.map(arc_key);
+            arc.edge = arc.edge.map(|ab_ba| edge_keys[&ab_ba]);
+            arc.face = arc.face.map(|abc| face_keys[&abc]);
+            let key = arc_key(ab);
+            storages[index].1.insert_with_key(&key, arc);
+            keys.insert(ab.into(), (index, key.into()));
+        }
+        for (a, b) in vertex_keys.iter() {
+            let vertex = storages[partition[a]].0.get_mut(b).expect_consistent();
+            vertex.arc = vertex.arc.map(arc_key);
+        }
+        let graphs = storages
+            .into_iter()
+            .map(|(vertices, arcs, edges, faces)| {
+                MeshGraph::from(
+                    Core::empty()
+                        .fuse(vertices)
+                        .fuse(arcs)
+                        .fuse(edges)
+                        .fuse(faces),
+                )
+            })
+            .collect();
+        (graphs, keys)
     }
 
     /// Shrinks the capacity of the graph's underlying storage as much as
@@ -1701,15 +3318,34 @@ mod tests {
     use num::Zero;
 
     use crate::buffer::MeshBuffer3;
-    use crate::graph::{GraphData, GraphError, MeshGraph};
+    use crate::graph::{
+        BoundaryKind, DualBoundary, GraphData, GraphError, GraphKey, HoleFill, MeshGraph,
+    };
     use crate::prelude::*;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
-    use crate::primitive::NGon;
+    use crate::primitive::{NGon, Tetragon, Trigon};
 
     type E2 = Point2<R64>;
     type E3 = Point3<R64>;
 
+    // Constructs a grid of quadrilaterals in the plane that are split along
+    // their diagonals.
+    pub(in crate::graph) fn grid(n: u32) -> MeshGraph<Point3<f64>> {
+        let indices = (0..(n - 1))
+            .flat_map(|y| {
+                (0..(n - 1)).flat_map(move |x| {
+                    let k = (y * n) + x;
+                    [NGon([k, k + 1, k + n + 1]), NGon([k, k + n + 1, k + n])]
+                })
+            })
+            .collect::<Vec<_>>();
+        let vertices = (0..n)
+            .flat_map(|y| (0..n).map(move |x| (f64::from(x), f64::from(y), 0.0)))
+            .collect::<Vec<_>>();
+        MeshGraph::<Point3<f64>>::from_raw_buffers(indices, vertices).unwrap()
+    }
+
     #[test]
     fn collect() {
         let graph: MeshGraph<Point3<f64>> = UvSphere::new(3, 2)
@@ -1771,6 +3407,527 @@ mod tests {
         assert_eq!(2, graph.disjoint_subgraph_vertices().count());
     }
 
+    #[test]
+    fn into_disjoint_subgraphs() {
+        // Construct a graph with two disjoint quadrilaterals.
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![NGon([0u32, 1, 2, 3]), NGon([4, 5, 6, 7])],
+            vec![
+                (-2.0, 0.0),
+                (-1.0, 0.0),
+                (-1.0, 1.0),
+                (-2.0, 1.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (2.0, 1.0),
+                (1.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let n = graph.vertex_count() + graph.arc_count() + graph.edge_count() + graph.face_count();
+        let key = graph.vertices().nth(0).unwrap().key();
+        let position = *graph.vertex(key).unwrap().get();
+
+        let (graphs, keys) = graph.into_disjoint_subgraphs();
+
+        assert_eq!(2, graphs.len());
+        assert_eq!(n, keys.len());
+        for graph in graphs.iter() {
+            assert_eq!(4, graph.vertex_count());
+            assert_eq!(8, graph.arc_count());
+            assert_eq!(1, graph.face_count());
+            assert_eq!(1, graph.disjoint_subgraph_vertices().count());
+            for face in graph.faces() {
+                assert!(face.adjacent_arcs().all(|arc| arc.face().is_some()));
+            }
+        }
+        // Data is moved along with entities.
+        let (index, key) = keys[&key.into()];
+        let key = match key {
+            GraphKey::Vertex(key) => key,
+            _ => panic!(),
+        };
+        assert_eq!(position, *graphs[index].vertex(key).unwrap().get());
+    }
+
+    #[test]
+    fn subdivide_catmull_clark_closed() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(4, 4).polygons::<Position<E3>>().collect();
+        let (vertices, edges, faces) =
+            (graph.vertex_count(), graph.edge_count(), graph.face_count());
+        let arity = graph.faces().map(|face| face.arity()).sum::<usize>();
+        graph.subdivide_catmull_clark(1);
+
+        assert_eq!(vertices + edges + faces, graph.vertex_count());
+        assert_eq!(arity, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+    }
+
+    #[test]
+    fn subdivide_catmull_clark_open() {
+        let mut graph = MeshGraph::<Point2<f64>>::from(Tetragon::from([
+            (1.0, 1.0),
+            (-1.0, 1.0),
+            (-1.0, -1.0),
+            (1.0, -1.0),
+        ]));
+        graph.subdivide_catmull_clark(1);
+
+        assert_eq!(9, graph.vertex_count());
+        assert_eq!(4, graph.face_count());
+        // Boundary vertices are only influenced by the boundary.
+        assert_eq!(
+            4,
+            graph
+                .vertices()
+                .filter(|vertex| {
+                    let position = vertex.position();
+                    position.x.abs() == 0.75 && position.y.abs() == 0.75
+                })
+                .count()
+        );
+    }
+
+    #[test]
+    fn subdivide_catmull_clark_with_faceless_edges() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3), Tetragon::new(4, 5, 6, 7)],
+            vec![
+                (1.0, 1.0),
+                (-1.0, 1.0),
+                (-1.0, -1.0),
+                (1.0, -1.0),
+                (4.0, 1.0),
+                (2.0, 1.0),
+                (2.0, -1.0),
+                (4.0, -1.0),
+            ],
+        )
+        .unwrap();
+        // Removing a vertex of a quadrilateral leaves a path of edges that are
+        // not adjacent to any face.
+        let key = graph
+            .vertices()
+            .find(|vertex| vertex.position() == &Point2::new(4.0, 1.0))
+            .unwrap()
+            .key();
+        graph.vertex_mut(key).unwrap().remove();
+        graph.subdivide_catmull_clark(1);
+
+        assert_eq!(9, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(4, graph.face_count());
+    }
+
+    #[test]
+    fn subdivide_loop() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(3, 2)
+            .polygons::<Position<E3>>() // 6 triangles, 18 vertices.
+            .collect();
+        graph.subdivide_loop(1).unwrap();
+
+        assert_eq!(14, graph.vertex_count());
+        assert_eq!(36, graph.edge_count());
+        assert_eq!(24, graph.face_count());
+    }
+
+    #[test]
+    fn subdivide_loop_with_faceless_edges() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(3, 4, 5)],
+            vec![
+                (-1.0, 0.0),
+                (0.0, 1.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (3.0, 1.0),
+                (4.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let key = graph
+            .vertices()
+            .find(|vertex| vertex.position() == &Point2::new(2.0, 0.0))
+            .unwrap()
+            .key();
+        graph.vertex_mut(key).unwrap().remove();
+        graph.subdivide_loop(1).unwrap();
+
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(9, graph.edge_count());
+        assert_eq!(4, graph.face_count());
+    }
+
+    #[test]
+    fn subdivide_loop_arity_conflict() {
+        let mut graph = MeshGraph::<Point2<f64>>::from(Tetragon::from([
+            (1.0, 1.0),
+            (-1.0, 1.0),
+            (-1.0, -1.0),
+            (1.0, -1.0),
+        ]));
+
+        assert_eq!(
+            Err(GraphError::ArityConflict {
+                expected: 3,
+                actual: 4,
+            }),
+            graph.subdivide_loop(1),
+        );
+        assert_eq!(1, graph.face_count());
+    }
+
+    #[test]
+    fn subdivide_doo_sabin_closed() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(3, 2)
+            .polygons::<Position<E3>>() // 6 triangles, 18 vertices.
+            .collect();
+        graph.subdivide_doo_sabin(1);
+
+        assert_eq!(18, graph.vertex_count());
+        assert_eq!(20, graph.face_count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+    }
+
+    #[test]
+    fn subdivide_doo_sabin_open() {
+        let mut graph = MeshGraph::<Point2<f64>>::from(Tetragon::from([
+            (1.0, 1.0),
+            (-1.0, 1.0),
+            (-1.0, -1.0),
+            (1.0, -1.0),
+        ]));
+        graph.subdivide_doo_sabin(1);
+
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+        assert!(graph.vertices().all(|vertex| {
+            let position = vertex.position();
+            position.x.abs() == 0.5 && position.y.abs() == 0.5
+        }));
+    }
+
+    #[test]
+    fn subdivide_sqrt3() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(3, 2)
+            .polygons::<Position<E3>>() // 6 triangles, 18 vertices.
+            .collect();
+        graph.subdivide_sqrt3(1).unwrap();
+
+        assert_eq!(11, graph.vertex_count());
+        assert_eq!(27, graph.edge_count());
+        assert_eq!(18, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        // Each vertex inserted at the centroid of a face is connected to the
+        // vertices inserted for neighboring faces.
+        assert_eq!(
+            6,
+            graph
+                .vertices()
+                .filter(|vertex| vertex.valence() == 6)
+                .count()
+        );
+    }
+
+    #[test]
+    fn to_dual_closed() {
+        let graph: MeshGraph<E3> = UvSphere::new(4, 4).polygons::<Position<E3>>().collect();
+        let dual = graph.to_dual(DualBoundary::Skip).unwrap();
+
+        assert_eq!(graph.face_count(), dual.vertex_count());
+        assert_eq!(graph.edge_count(), dual.edge_count());
+        assert_eq!(graph.vertex_count(), dual.face_count());
+        assert!(dual.edges().all(|edge| !edge.is_boundary_edge()));
+        // The dual of the dual is topologically equivalent to the graph.
+        let graph = dual.to_dual(DualBoundary::Skip).unwrap();
+        assert_eq!(dual.face_count(), graph.vertex_count());
+        assert_eq!(dual.vertex_count(), graph.face_count());
+    }
+
+    #[test]
+    fn to_dual_open() {
+        // Create a 2x2 grid of quadrilaterals.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![
+                NGon([0usize, 1, 4, 3]),
+                NGon([1, 2, 5, 4]),
+                NGon([3, 4, 7, 6]),
+                NGon([4, 5, 8, 7]),
+            ],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (0.0, 1.0),
+                (1.0, 1.0),
+                (2.0, 1.0),
+                (0.0, 2.0),
+                (1.0, 2.0),
+                (2.0, 2.0),
+            ],
+        )
+        .unwrap();
+
+        let dual = graph.to_dual(DualBoundary::Skip).unwrap();
+        assert_eq!(4, dual.vertex_count());
+        assert_eq!(1, dual.face_count());
+
+        // Faces are inserted for the 8 boundary vertices, which are closed by
+        // vertices at the midpoints of the 8 boundary edges.
+        let dual = graph.to_dual(DualBoundary::Close).unwrap();
+        assert_eq!(20, dual.vertex_count());
+        assert_eq!(9, dual.face_count());
+        assert_eq!(
+            4,
+            dual.faces()
+                .filter(|face| face.arity() == 4 && face.adjacent_faces().count() == 2)
+                .count()
+        );
+    }
+
+    #[test]
+    fn to_dual_open_strip() {
+        // Create a 1x3 strip of quadrilaterals, which has no interior vertices.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![
+                NGon([0usize, 1, 5, 4]),
+                NGon([1, 2, 6, 5]),
+                NGon([2, 3, 7, 6]),
+            ],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (3.0, 0.0),
+                (0.0, 1.0),
+                (1.0, 1.0),
+                (2.0, 1.0),
+                (3.0, 1.0),
+            ],
+        )
+        .unwrap();
+
+        let dual = graph.to_dual(DualBoundary::Skip).unwrap();
+        assert_eq!(0, dual.vertex_count());
+        assert_eq!(0, dual.face_count());
+
+        // Faces are inserted for the 8 boundary vertices, which are closed by
+        // vertices at the midpoints of the 8 boundary edges.
+        let dual = graph.to_dual(DualBoundary::Close).unwrap();
+        assert_eq!(19, dual.vertex_count());
+        assert_eq!(8, dual.face_count());
+    }
+
+    #[test]
+    fn fill_holes() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(8, 8)
+            .polygons::<Position<E3>>()
+            .skip(1)
+            .collect();
+
+        // Holes with an arity that exceeds the given arity are not filled.
+        assert_eq!(0, graph.fill_holes(2, HoleFill::Triangulate).unwrap());
+        assert_eq!(1, graph.fill_holes(8, HoleFill::Triangulate).unwrap());
+        assert_eq!(0, graph.fill_holes(8, HoleFill::Triangulate).unwrap());
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+    }
+
+    #[test]
+    fn fill_holes_of_open_surface() {
+        // A grid of three by three quadrilaterals with the center removed.
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            (0u32..3)
+                .flat_map(|j| (0u32..3).map(move |i| (i, j)))
+                .filter(|&(i, j)| (i, j) != (1, 1))
+                .map(|(i, j)| {
+                    let k = (j * 4) + i;
+                    Tetragon::new(k, k + 1, k + 5, k + 4)
+                })
+                .collect::<Vec<_>>(),
+            (0..4)
+                .flat_map(|y| (0..4).map(move |x| (f64::from(x), f64::from(y))))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+
+        // The outer boundary is not filled even though its arity does not
+        // exceed the given arity.
+        assert_eq!(1, graph.fill_holes(12, HoleFill::Triangulate).unwrap());
+        assert_eq!(1, graph.boundaries().len());
+    }
+
+    #[test]
+    fn boundaries() {
+        // A grid of three by three quadrilaterals with the center removed.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            (0u32..3)
+                .flat_map(|j| (0u32..3).map(move |i| (i, j)))
+                .filter(|&(i, j)| (i, j) != (1, 1))
+                .map(|(i, j)| {
+                    let k = (j * 4) + i;
+                    Tetragon::new(k, k + 1, k + 5, k + 4)
+                })
+                .collect::<Vec<_>>(),
+            (0..4)
+                .flat_map(|y| (0..4).map(move |x| (f64::from(x), f64::from(y))))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let mut boundaries = graph
+            .boundaries()
+            .map(|ring| (ring.arity(), ring.length(), ring.boundary_kind().unwrap()))
+            .collect::<Vec<_>>();
+        boundaries.sort_by_key(|(arity, _, _)| *arity);
+
+        assert_eq!(
+            vec![
+                (4, 4.0, BoundaryKind::Hole),
+                (12, 12.0, BoundaryKind::Outer),
+            ],
+            boundaries
+        );
+        // Rings with faces are not boundaries.
+        assert_eq!(
+            Err(GraphError::TopologyConflict),
+            graph.faces().nth(0).unwrap().ring().boundary_kind()
+        );
+    }
+
+    #[test]
+    fn split_at_path() {
+        // Construct a graph from two triangles that share an edge.
+        let mut graph = MeshGraph::<Point2<i32>>::from_raw_buffers(
+            vec![NGon([0u32, 1, 2]), NGon([2, 1, 3])],
+            vec![(-1, 0), (0, -1), (0, 1), (1, 0)],
+        )
+        .unwrap();
+        let key = graph
+            .edges()
+            .find(|edge| !edge.is_boundary_edge())
+            .map(|edge| edge.into_arc().key())
+            .unwrap();
+        let path = graph.arc_mut(key).unwrap().into_path();
+        MeshGraph::split_at_path(path).unwrap();
+
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(6, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+        assert_eq!(2, graph.disjoint_subgraph_vertices().count());
+    }
+
+    #[test]
+    fn split_at_interior_path() {
+        // Construct a graph resembling the following diagram.
+        //
+        //   0---1---2
+        //   |\  |  /|
+        //   | \ | / |
+        //   |  \|/  |
+        //   3---4---5
+        //   |  /|\  |
+        //   | / | \ |
+        //   |/  |  \|
+        //   6---7---8
+        let grid = || {
+            MeshGraph::<Point2<i32>>::from_raw_buffers(
+                vec![
+                    NGon([0u32, 3, 4]),
+                    NGon([4, 1, 0]),
+                    NGon([1, 4, 2]),
+                    NGon([2, 4, 5]),
+                    NGon([4, 3, 6]),
+                    NGon([6, 7, 4]),
+                    NGon([4, 7, 8]),
+                    NGon([8, 5, 4]),
+                ],
+                vec![
+                    (0, 0),
+                    (1, 0),
+                    (2, 0),
+                    (0, 1),
+                    (1, 1),
+                    (2, 1),
+                    (0, 2),
+                    (1, 2),
+                    (2, 2),
+                ],
+            )
+            .unwrap()
+        };
+        let find = |graph: &MeshGraph<Point2<i32>>, x, y| {
+            graph
+                .vertices()
+                .find(|vertex| *vertex.get() == Point2::new(x, y))
+                .map(|vertex| vertex.key())
+                .unwrap()
+        };
+
+        // Cut a slit from the boundary to the center of the graph. The center
+        // vertex is not copied.
+        let mut slit = grid();
+        let keys = [find(&slit, 0, 1), find(&slit, 1, 1)];
+        let path = slit.path_mut(keys).unwrap();
+        MeshGraph::split_at_path(path).unwrap();
+
+        assert_eq!(10, slit.vertex_count());
+        assert_eq!(17, slit.edge_count());
+        assert_eq!(8, slit.face_count());
+        assert_eq!(1, slit.disjoint_subgraph_vertices().count());
+
+        // Cut across the graph. This bisects the graph.
+        let mut graph = grid();
+        let keys = [find(&graph, 0, 1), find(&graph, 1, 1), find(&graph, 2, 1)];
+        let path = graph.path_mut(keys).unwrap();
+        MeshGraph::split_at_path(path).unwrap();
+
+        assert_eq!(12, graph.vertex_count());
+        assert_eq!(8, graph.face_count());
+        assert_eq!(2, graph.disjoint_subgraph_vertices().count());
+    }
+
+    #[test]
+    fn reverse() {
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(8, 8)
+            .polygons::<Position<E3>>()
+            .skip(1)
+            .collect();
+        let normals = graph
+            .faces()
+            .map(|face| (face.key(), face.normal().unwrap()))
+            .collect::<Vec<_>>();
+        graph.reverse();
+
+        assert!(graph.validate().is_empty());
+        assert_eq!(8, graph.boundaries().nth(0).unwrap().arity());
+        for (key, normal) in normals {
+            let reversed = graph.face(key).unwrap().normal().unwrap();
+            assert!((normal + reversed).norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn orient() {
+        let mut graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(8, 8).polygons::<Position<E3>>().collect();
+        graph.reverse();
+
+        assert_eq!(1, graph.orient());
+        assert_eq!(0, graph.orient());
+        // The normals of faces point away from the center of the sphere.
+        for face in graph.faces() {
+            assert!(face.normal().unwrap().dot(&face.centroid().coords) > 0.0);
+        }
+
+        // Open sub-graphs are not reversed.
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(8, 8)
+            .polygons::<Position<E3>>()
+            .skip(1)
+            .collect();
+        graph.reverse();
+
+        assert_eq!(0, graph.orient());
+    }
+
     #[test]
     fn non_manifold_error_deferred() {
         let graph: MeshGraph<E3> = UvSphere::new(32, 32)
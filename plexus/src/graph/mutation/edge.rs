@@ -1,3 +1,6 @@
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+use std::mem;
 use std::ops::{Deref, DerefMut};
 
 use crate::entity::borrow::Reborrow;
@@ -7,14 +10,14 @@ use crate::entity::view::{Bind, ClosedView, Rebind};
 use crate::graph::core::Core;
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcView, Edge, EdgeKey};
-use crate::graph::face::{Face, FaceKey};
+use crate::graph::face::{Face, FaceKey, FaceView};
 use crate::graph::mutation::face::{self, FaceInsertCache, FaceRemoveCache};
 use crate::graph::mutation::vertex::{self, VertexMutation};
 use crate::graph::mutation::{Consistent, Immediate, Mode, Mutable, Mutation};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
 use crate::graph::GraphError;
 use crate::transact::{Bypass, Transact};
-use crate::IteratorExt as _;
+use crate::{DynamicArity, IteratorExt as _};
 
 pub type CompositeEdge<G> = (Edge<G>, (Arc<G>, Arc<G>));
 pub type CompositeEdgeData<G> = (
@@ -91,6 +94,34 @@ where
         Ok(xa)
     }
 
+    pub fn replace_arc_data(
+        &mut self,
+        ab: ArcKey,
+        data: <Data<P::Graph> as GraphData>::Arc,
+    ) -> Result<<Data<P::Graph> as GraphData>::Arc, GraphError> {
+        let arc = self
+            .storage
+            .0
+            .as_storage_mut()
+            .get_mut(&ab)
+            .ok_or(GraphError::TopologyNotFound)?;
+        Ok(mem::replace(&mut arc.data, data))
+    }
+
+    pub fn replace_edge_data(
+        &mut self,
+        ab_ba: EdgeKey,
+        data: <Data<P::Graph> as GraphData>::Edge,
+    ) -> Result<<Data<P::Graph> as GraphData>::Edge, GraphError> {
+        let edge = self
+            .storage
+            .1
+            .as_storage_mut()
+            .get_mut(&ab_ba)
+            .ok_or(GraphError::TopologyNotFound)?;
+        Ok(mem::replace(&mut edge.data, data))
+    }
+
     pub fn connect_arc_to_edge(&mut self, ab: ArcKey, ab_ba: EdgeKey) -> Result<(), GraphError> {
         self.with_arc_mut(ab, |arc| arc.edge = Some(ab_ba))
     }
@@ -415,6 +446,183 @@ impl ArcExtrudeCache {
     }
 }
 
+pub struct ArcCollapseCache {
+    a: VertexKey,
+    b: VertexKey,
+    faces: Vec<(FaceRemoveCache, SmallVec<[VertexKey; 4]>)>,
+}
+
+impl ArcCollapseCache {
+    pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Edge<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let source = arc.source_vertex();
+        let destination = arc.destination_vertex();
+        let a = source.key();
+        let b = destination.key();
+        let ab = arc.key();
+        let ba = arc.opposite_arc().key();
+        // Any arcs connected to the source vertex must be adjacent to a face.
+        if source
+            .outgoing_arcs()
+            .any(|arc| arc.face().is_none() && arc.opposite_arc().face().is_none())
+        {
+            return Err(GraphError::TopologyConflict);
+        }
+        // Collapsing an interior edge between boundary vertices pinches the
+        // surface.
+        if !(arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc())
+            && source.is_boundary_vertex()
+            && destination.is_boundary_vertex()
+        {
+            return Err(GraphError::TopologyConflict);
+        }
+        // Triangles adjacent to the edge degenerate and are removed, merging
+        // their remaining edges. Such merged edges must be adjacent to at
+        // least one face.
+        let mut apexes = HashSet::new();
+        let mut adjacent = HashSet::new();
+        for face in [arc.face(), arc.opposite_arc().face()]
+            .into_iter()
+            .flatten()
+        {
+            adjacent.insert(face.key());
+            if face.arity() == 3 {
+                if face
+                    .adjacent_arcs()
+                    .filter(|arc| arc.key() != ab && arc.key() != ba)
+                    .all(|arc| arc.opposite_arc().face().is_none())
+                {
+                    return Err(GraphError::TopologyConflict);
+                }
+                apexes.extend(
+                    face.adjacent_vertices()
+                        .keys()
+                        .filter(|key| *key != a && *key != b),
+                );
+            }
+        }
+        // Check the link condition: the only vertices adjacent to both vertices
+        // of the edge must be the apexes of adjacent triangles.
+        let neighbors = destination
+            .adjacent_vertices()
+            .keys()
+            .collect::<HashSet<_>>();
+        if source
+            .adjacent_vertices()
+            .keys()
+            .filter(|key| neighbors.contains(key))
+            .any(|key| !apexes.contains(&key))
+        {
+            return Err(GraphError::TopologyConflict);
+        }
+        // The apexes must not be joined by an edge that forms triangles with
+        // both vertices of the edge, such as in a tetrahedron. Collapsing such
+        // an edge folds these triangles onto each other.
+        if let [c, d] = apexes.iter().cloned().collect::<SmallVec<[_; 2]>>()[..] {
+            let triangles = source
+                .adjacent_faces()
+                .chain(destination.adjacent_faces())
+                .filter(|face| face.arity() == 3)
+                .map(|face| face.adjacent_vertices().keys().collect::<HashSet<_>>())
+                .collect::<Vec<_>>();
+            let is_triangle = |key| {
+                triangles
+                    .iter()
+                    .any(|triangle| [key, c, d].iter().all(|key| triangle.contains(key)))
+            };
+            if is_triangle(a) && is_triangle(b) {
+                return Err(GraphError::TopologyConflict);
+            }
+        }
+        let mut faces = Vec::new();
+        for face in source.adjacent_faces() {
+            let perimeter = face
+                .adjacent_vertices()
+                .keys()
+                .collect::<SmallVec<[_; 4]>>();
+            // Faces that include both vertices but are not adjacent to the edge
+            // would be split into two rings.
+            if !adjacent.contains(&face.key()) && perimeter.contains(&b) {
+                return Err(GraphError::TopologyConflict);
+            }
+            faces.push((FaceRemoveCache::from_face(face)?, perimeter));
+        }
+        Ok(ArcCollapseCache { a, b, faces })
+    }
+}
+
+pub struct ArcFlipCache {
+    ab: ArcKey,
+    c: VertexKey,
+    d: VertexKey,
+    faces: (FaceRemoveCache, FaceRemoveCache),
+}
+
+impl ArcFlipCache {
+    pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Edge<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let a = arc.source_vertex().key();
+        let b = arc.destination_vertex().key();
+        let (left, right) = match (arc.face(), arc.opposite_arc().face()) {
+            (Some(left), Some(right)) => (left, right),
+            _ => return Err(GraphError::TopologyConflict),
+        };
+        for face in [left.to_ref(), right.to_ref()] {
+            if face.arity() != 3 {
+                return Err(GraphError::ArityConflict {
+                    expected: 3,
+                    actual: face.arity(),
+                });
+            }
+        }
+        let apex = |face: FaceView<_>| {
+            face.adjacent_vertices()
+                .keys()
+                .find(|key| *key != a && *key != b)
+                .ok_or(GraphError::TopologyMalformed)
+        };
+        let c = apex(left.to_ref())?;
+        let d = apex(right.to_ref())?;
+        // The opposing diagonal must not already be an edge.
+        if c == d
+            || left
+                .adjacent_vertices()
+                .find(|vertex| vertex.key() == c)
+                .ok_or(GraphError::TopologyMalformed)?
+                .adjacent_vertices()
+                .keys()
+                .any(|key| key == d)
+        {
+            return Err(GraphError::TopologyConflict);
+        }
+        Ok(ArcFlipCache {
+            ab: arc.key(),
+            c,
+            d,
+            faces: (
+                FaceRemoveCache::from_face(left)?,
+                FaceRemoveCache::from_face(right)?,
+            ),
+        })
+    }
+}
+
 pub fn get_or_insert_with<N, P, F>(
     mut mutation: N,
     endpoints: (VertexKey, VertexKey),
@@ -549,6 +757,189 @@ where
     ))
 }
 
+/// Removes an edge that is not adjacent to any face.
+///
+/// Unlike `remove`, this reads connectivity from the storage of the mutation
+/// rather than a cache and so can be used while topology is being rebuilt.
+/// Vertices that are left without any arcs are disconnected from their leading
+/// arc, but are **not** removed.
+pub fn remove_faceless<N, P>(
+    mut mutation: N,
+    ab: ArcKey,
+) -> Result<CompositeEdge<Data<P::Graph>>, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+{
+    let (a, b) = ab.into();
+    let ba = ab.into_opposite();
+    let ((xa, bx, ab_ba), (xb, ax)) = {
+        let arcs = mutation.as_mut().storage.0.as_storage();
+        let arc = arcs.get(&ab).ok_or(GraphError::TopologyNotFound)?;
+        let opposite = arcs.get(&ba).ok_or(GraphError::TopologyMalformed)?;
+        if arc.face.is_some() || opposite.face.is_some() {
+            return Err(GraphError::TopologyConflict);
+        }
+        (
+            (
+                arc.previous,
+                arc.next,
+                arc.edge.ok_or(GraphError::TopologyMalformed)?,
+            ),
+            (opposite.previous, opposite.next),
+        )
+    };
+    // Connect the arcs that are adjacent to the edge and each vertex to a
+    // remaining outgoing arc. If the previous arc of AB is BA, then A has no
+    // other arcs (and likewise for B).
+    match (xa, ax) {
+        (Some(xa), Some(ax)) if xa != ba => {
+            mutation.as_mut().connect_adjacent_arcs(xa, ax)?;
+            mutation.as_mut().connect_outgoing_arc(a, ax)?;
+        }
+        _ => {
+            mutation.as_mut().disconnect_outgoing_arc(a)?;
+        }
+    }
+    match (xb, bx) {
+        (Some(xb), Some(bx)) if bx != ba => {
+            mutation.as_mut().connect_adjacent_arcs(xb, bx)?;
+            mutation.as_mut().connect_outgoing_arc(b, bx)?;
+        }
+        _ => {
+            mutation.as_mut().disconnect_outgoing_arc(b)?;
+        }
+    }
+    let edge = mutation
+        .as_mut()
+        .storage
+        .1
+        .as_storage_mut()
+        .remove(&ab_ba)
+        .ok_or(GraphError::TopologyMalformed)?;
+    let arc = mutation
+        .as_mut()
+        .storage
+        .0
+        .as_storage_mut()
+        .remove(&ab)
+        .ok_or(GraphError::TopologyMalformed)?;
+    let opposite = mutation
+        .as_mut()
+        .storage
+        .0
+        .as_storage_mut()
+        .remove(&ba)
+        .ok_or(GraphError::TopologyMalformed)?;
+    Ok((edge, (arc, opposite)))
+}
+
+/// Removes the edges of arcs that are not adjacent to any face.
+///
+/// Arcs that are adjacent to a face or that have already been removed are
+/// ignored. This is typically used to remove the edges along the perimeters of
+/// faces that have been removed.
+pub fn remove_faceless_edges<N, P, I>(mut mutation: N, arcs: I) -> Result<(), GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+    I: IntoIterator<Item = ArcKey>,
+{
+    for ab in arcs {
+        let is_faceless = {
+            let core = mutation.as_mut().to_ref_core();
+            match (core.arcs.get(&ab), core.arcs.get(&ab.into_opposite())) {
+                (Some(arc), Some(opposite)) => arc.face.is_none() && opposite.face.is_none(),
+                _ => false,
+            }
+        };
+        if is_faceless {
+            remove_faceless(mutation.as_mut(), ab)?;
+        }
+    }
+    Ok(())
+}
+
+/// Copies of the data of arcs and their edges.
+///
+/// Operations that remove and reinsert faces copy the data of the arcs and
+/// edges along the perimeters of the faces before they are removed and then
+/// restore that data to the arcs and edges of the reinserted faces.
+pub struct ArcDataCache<P>
+where
+    P: Mode,
+{
+    data: HashMap<
+        ArcKey,
+        (
+            <Data<P::Graph> as GraphData>::Arc,
+            <Data<P::Graph> as GraphData>::Edge,
+        ),
+    >,
+}
+
+impl<P> ArcDataCache<P>
+where
+    P: Mode,
+    P::Graph: Mutable,
+{
+    /// Copies the data of the given arcs, their opposite arcs, and their
+    /// edges.
+    pub fn from_arcs<N, I>(mut mutation: N, arcs: I) -> Result<Self, GraphError>
+    where
+        N: AsMut<Mutation<P>>,
+        I: IntoIterator<Item = ArcKey>,
+    {
+        let mut data = HashMap::new();
+        let core = mutation.as_mut().to_ref_core();
+        for ab in arcs.into_iter().flat_map(|ab| [ab, ab.into_opposite()]) {
+            if data.contains_key(&ab) {
+                continue;
+            }
+            let arc = core.arcs.get(&ab).ok_or(GraphError::TopologyNotFound)?;
+            let edge = arc
+                .edge
+                .and_then(|ab_ba| core.edges.get(&ab_ba))
+                .ok_or(GraphError::TopologyMalformed)?;
+            data.insert(ab, (arc.data.clone(), edge.data.clone()));
+        }
+        Ok(ArcDataCache { data })
+    }
+
+    pub fn contains_arc(&self, ab: ArcKey) -> bool {
+        self.data.contains_key(&ab)
+    }
+
+    /// Restores the data copied from the arc `source` and its edge to the arc
+    /// `destination` and its edge.
+    ///
+    /// Does nothing if no data has been copied from `source`.
+    pub fn restore<N>(
+        &self,
+        mut mutation: N,
+        source: ArcKey,
+        destination: ArcKey,
+    ) -> Result<(), GraphError>
+    where
+        N: AsMut<Mutation<P>>,
+    {
+        if let Some((arc, edge)) = self.data.get(&source).cloned() {
+            let ab_ba = mutation
+                .as_mut()
+                .to_ref_core()
+                .arcs
+                .get(&destination)
+                .and_then(|arc| arc.edge)
+                .ok_or(GraphError::TopologyMalformed)?;
+            mutation.as_mut().replace_arc_data(destination, arc)?;
+            mutation.as_mut().replace_edge_data(ab_ba, edge)?;
+        }
+        Ok(())
+    }
+}
+
 pub fn split_with<N, P, F>(
     mut mutation: N,
     cache: EdgeSplitCache,
@@ -654,6 +1045,159 @@ where
     Ok(m)
 }
 
+// The identifiers `a`, `b`, `x`, and `y` are probably well understood in this
+// context and `f` is used in a manner that is consistent with the standard
+// library.
+#[allow(clippy::many_single_char_names)]
+pub fn collapse_with<N, P, F>(
+    mut mutation: N,
+    cache: ArcCollapseCache,
+    f: F,
+) -> Result<VertexKey, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+    F: FnOnce(
+        &<Data<P::Graph> as GraphData>::Vertex,
+        &<Data<P::Graph> as GraphData>::Vertex,
+    ) -> <Data<P::Graph> as GraphData>::Vertex,
+{
+    let ArcCollapseCache { a, b, faces } = cache;
+    let data = {
+        let source = VertexView::bind(mutation.as_mut(), a)
+            .ok_or(GraphError::TopologyNotFound)?
+            .get()
+            .clone();
+        let destination = VertexView::bind(mutation.as_mut(), b)
+            .ok_or(GraphError::TopologyNotFound)?
+            .get()
+            .clone();
+        f(&source, &destination)
+    };
+    // Copy the data of the arcs and edges along the perimeters of the faces
+    // before they are removed.
+    let arcs = faces
+        .iter()
+        .flat_map(|(_, perimeter)| perimeter.iter().cloned().perimeter().map(ArcKey::from))
+        .collect::<Vec<_>>();
+    let edges = ArcDataCache::from_arcs(mutation.as_mut(), arcs.iter().cloned())?;
+    // Remove the faces around the source vertex and then any edges that are no
+    // longer adjacent to a face. This disconnects the source vertex.
+    let mut faces = faces
+        .into_iter()
+        .map(|(cache, perimeter)| {
+            face::remove(mutation.as_mut(), cache).map(|face| (face.data, perimeter))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    remove_faceless_edges(mutation.as_mut(), arcs)?;
+    vertex::remove_disjoint(mutation.as_mut(), a)?;
+    mutation.as_mut().replace_vertex_data(b, data)?;
+    // Reinsert the faces with the source vertex replaced by the destination
+    // vertex. Faces are inserted such that they are adjacent to existing faces
+    // when possible, which avoids pinching vertices while rebuilding.
+    let replace = |x: VertexKey| if x == a { b } else { x };
+    while !faces.is_empty() {
+        let index = {
+            let core = mutation.as_mut().to_ref_core();
+            faces
+                .iter()
+                .position(|(_, perimeter)| {
+                    perimeter
+                        .iter()
+                        .cloned()
+                        .map(replace)
+                        .perimeter()
+                        .any(|(x, y)| {
+                            core.arcs
+                                .get(&(y, x).into())
+                                .map_or(false, |arc| arc.face.is_some())
+                        })
+                })
+                .unwrap_or(0)
+        };
+        let (data, perimeter) = faces.remove(index);
+        let keys = perimeter
+            .iter()
+            .cloned()
+            .map(replace)
+            .perimeter()
+            .filter(|(x, y)| x != y)
+            .map(|(x, _)| x)
+            .collect::<SmallVec<[_; 4]>>();
+        // Triangles adjacent to the collapsed edge degenerate.
+        if keys.len() < 3 {
+            continue;
+        }
+        let cache = FaceInsertCache::from_storage(mutation.as_mut(), &keys)?;
+        face::insert_with(mutation.as_mut(), cache, || (Default::default(), data))?;
+        // Restore the data of the arcs and edges of the face. Arcs that existed
+        // before the collapse keep their data.
+        for (x, y) in perimeter.iter().cloned().perimeter() {
+            let (u, v) = (replace(x), replace(y));
+            if u == v {
+                continue;
+            }
+            for (source, destination) in [((x, y), (u, v)), ((y, x), (v, u))] {
+                let destination = ArcKey::from(destination);
+                let source = if edges.contains_arc(destination) {
+                    destination
+                }
+                else {
+                    source.into()
+                };
+                edges.restore(mutation.as_mut(), source, destination)?;
+            }
+        }
+    }
+    Ok(b)
+}
+
+pub fn flip<N, P>(mut mutation: N, cache: ArcFlipCache) -> Result<ArcKey, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+{
+    let ArcFlipCache { ab, c, d, faces } = cache;
+    let (a, b) = ab.into();
+    let ba = ab.into_opposite();
+    // Copy the data of the arcs and edges of the quadrilateral before the
+    // faces are removed.
+    let arcs = [a, d, b, c]
+        .iter()
+        .cloned()
+        .perimeter()
+        .map(ArcKey::from)
+        .collect::<Vec<_>>();
+    let edges = ArcDataCache::from_arcs(mutation.as_mut(), arcs.iter().cloned().chain(Some(ab)))?;
+    let left = face::remove(mutation.as_mut(), faces.0)?.data;
+    let right = face::remove(mutation.as_mut(), faces.1)?.data;
+    remove_faceless_edges(mutation.as_mut(), Some(ab).into_iter().chain(arcs))?;
+    // Insert the faces formed by the opposing diagonal. The faces take the data
+    // of the faces that share their leading arcs and the arcs and edge of the
+    // diagonal take the data of the removed arcs and edge.
+    let cache = FaceInsertCache::from_storage(mutation.as_mut(), &[c, a, d])?;
+    face::insert_with(mutation.as_mut(), cache, || (Default::default(), left))?;
+    let cache = FaceInsertCache::from_storage(mutation.as_mut(), &[d, b, c])?;
+    face::insert_with(mutation.as_mut(), cache, || (Default::default(), right))?;
+    let dc = ArcKey::from((d, c));
+    let cd = dc.into_opposite();
+    for (source, destination) in [a, d, b, c]
+        .iter()
+        .cloned()
+        .perimeter()
+        .flat_map(|(x, y)| {
+            let xy = ArcKey::from((x, y));
+            [(xy, xy), (xy.into_opposite(), xy.into_opposite())]
+        })
+        .chain([(ab, dc), (ba, cd)])
+    {
+        edges.restore(mutation.as_mut(), source, destination)?;
+    }
+    Ok(dc)
+}
+
 pub fn bridge<N, P>(mut mutation: N, cache: ArcBridgeCache) -> Result<FaceKey, GraphError>
 where
     N: AsMut<Mutation<P>>,
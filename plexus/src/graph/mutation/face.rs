@@ -449,6 +449,88 @@ impl FacePokeCache {
     }
 }
 
+pub struct FaceTriangulateCache {
+    vertices: Vec<VertexKey>,
+    steiner: usize,
+    triangles: Vec<[usize; 3]>,
+    cache: FaceRemoveCache,
+}
+
+impl FaceTriangulateCache {
+    /// Creates a cache for the given triangles.
+    ///
+    /// Triangles are given as indices into the vertices of the face's
+    /// perimeter followed by the given number of Steiner vertices, which are
+    /// inserted into the interior of the face.
+    pub fn from_face<B>(
+        face: FaceView<B>,
+        steiner: usize,
+        triangles: Vec<[usize; 3]>,
+    ) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let vertices = face.adjacent_vertices().keys().collect::<Vec<_>>();
+        let n = vertices.len();
+        let edge = |a: usize, b: usize| if a < b { (a, b) } else { (b, a) };
+        let is_perimeter = |a: usize, b: usize| (a + 1) % n == b || (b + 1) % n == a;
+        for triangle in triangles.iter() {
+            if triangle.iter().any(|&index| index >= n + steiner) {
+                return Err(GraphError::TopologyNotFound);
+            }
+            // Diagonals between vertices in the perimeter must not already
+            // exist, because they would intersect another face.
+            for (a, b) in triangle.iter().cloned().perimeter() {
+                if a < n && b < n && !is_perimeter(a, b) {
+                    let ab = (vertices[a], vertices[b]).into();
+                    if Rebind::<_, ArcView<_>>::rebind(face.to_ref(), ab).is_some() {
+                        return Err(GraphError::TopologyConflict);
+                    }
+                }
+            }
+        }
+        // Order the triangles such that each triangle shares an edge with the
+        // perimeter or a previously ordered triangle at each of its vertices.
+        // Otherwise, inserted faces may meet at only a vertex and pinch it.
+        let mut edges = (0..n).map(|a| edge(a, (a + 1) % n)).collect::<HashSet<_>>();
+        let mut is_connected = (0..(n + steiner))
+            .map(|index| index < n)
+            .collect::<Vec<_>>();
+        let mut unordered = triangles;
+        let mut triangles = Vec::with_capacity(unordered.len());
+        while !unordered.is_empty() {
+            let index = unordered
+                .iter()
+                .position(|triangle| {
+                    (0..3).all(|k| {
+                        let (a, b, c) = (triangle[(k + 2) % 3], triangle[k], triangle[(k + 1) % 3]);
+                        !is_connected[b]
+                            || edges.contains(&edge(a, b))
+                            || edges.contains(&edge(b, c))
+                    })
+                })
+                .unwrap_or(0);
+            let triangle = unordered.remove(index);
+            for (a, b) in triangle.iter().cloned().perimeter() {
+                edges.insert(edge(a, b));
+                is_connected[a] = true;
+            }
+            triangles.push(triangle);
+        }
+        Ok(FaceTriangulateCache {
+            vertices,
+            steiner,
+            triangles,
+            cache: FaceRemoveCache::from_face(face)?,
+        })
+    }
+}
+
 pub struct FaceBridgeCache {
     source: SmallVec<[ArcKey; 4]>,
     destination: SmallVec<[ArcKey; 4]>,
@@ -506,6 +588,77 @@ impl FaceExtrudeCache {
     }
 }
 
+pub struct FaceReverseCache {
+    faces: Vec<(FaceKey, ArcKey)>,
+    arcs: Vec<(ArcKey, Option<FaceKey>, ArcKey)>,
+}
+
+impl FaceReverseCache {
+    pub fn from_face<B>(face: FaceView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let (storage, abc) = face.unbind();
+        FaceReverseCache::from_storage(storage, &[abc])
+    }
+
+    pub fn from_storage<B, K>(storage: B, faces: K) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+        K: IntoIterator,
+        K::Item: Borrow<FaceKey>,
+    {
+        let storage = storage.reborrow();
+        let faces = faces
+            .into_iter()
+            .map(|key| FaceView::bind(storage, *key.borrow()).ok_or(GraphError::TopologyNotFound))
+            .collect::<Result<Vec<_>, _>>()?;
+        let keys = faces.iter().map(|face| face.key()).collect::<HashSet<_>>();
+        let mut visited = faces
+            .iter()
+            .flat_map(|face| face.adjacent_arcs().keys().collect::<Vec<_>>())
+            .flat_map(|ab| [ab, ab.into_opposite()])
+            .collect::<HashSet<_>>();
+        let mut queue = visited.iter().cloned().collect::<Vec<_>>();
+        let mut arcs = Vec::with_capacity(queue.len());
+        while let Some(ab) = queue.pop() {
+            let arc = ArcView::bind(storage, ab).ok_or(GraphError::TopologyNotFound)?;
+            if arc.face.map_or(false, |abc| !keys.contains(&abc)) {
+                // The arc is part of a face that is not reversed, so the
+                // winding of the faces conflicts with their neighbors.
+                return Err(GraphError::TopologyConflict);
+            }
+            // Faceless arcs that are connected to reversed arcs must also be
+            // reversed. Any such arc that leads to a face that is not reversed
+            // is rejected above.
+            let (xa, bx) = (arc.previous_arc().key(), arc.next_arc().key());
+            for key in [xa, xa.into_opposite(), bx, bx.into_opposite()] {
+                if visited.insert(key) {
+                    queue.push(key);
+                }
+            }
+            arcs.push((ab, arc.face, xa));
+        }
+        Ok(FaceReverseCache {
+            faces: faces
+                .into_iter()
+                .map(|face| (face.key(), face.arc))
+                .collect(),
+            arcs,
+        })
+    }
+}
+
 // TODO: Should this accept arc data at all?
 pub fn insert_with<N, P, F>(
     mut mutation: N,
@@ -613,6 +766,39 @@ where
     Ok(c)
 }
 
+pub fn triangulate_with<N, P, F>(
+    mut mutation: N,
+    cache: FaceTriangulateCache,
+    mut f: F,
+) -> Result<Vec<FaceKey>, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+    F: FnMut(usize) -> <Data<P::Graph> as GraphData>::Vertex,
+{
+    let FaceTriangulateCache {
+        mut vertices,
+        steiner,
+        triangles,
+        cache,
+    } = cache;
+    let face = remove(mutation.as_mut(), cache)?;
+    for index in 0..steiner {
+        vertices.push(vertex::insert(mutation.as_mut(), f(index)));
+    }
+    triangles
+        .into_iter()
+        .map(|triangle| {
+            let perimeter = triangle.map(|index| vertices[index]);
+            let cache = FaceInsertCache::from_storage(mutation.as_mut(), &perimeter)?;
+            insert_with(mutation.as_mut(), cache, || {
+                (Default::default(), face.data.clone())
+            })
+        })
+        .collect()
+}
+
 pub fn bridge<N, P>(mut mutation: N, cache: FaceBridgeCache) -> Result<(), GraphError>
 where
     N: AsMut<Mutation<P>>,
@@ -683,3 +869,34 @@ where
     }
     Ok(extrusion)
 }
+
+pub fn reverse<N, P>(mut mutation: N, cache: FaceReverseCache) -> Result<(), GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+{
+    let FaceReverseCache { faces, arcs } = cache;
+    // Given an arc AB with previous arc XA, its opposite arc BA takes on the
+    // face of AB and is followed by the opposite arc AX. This reverses the
+    // rings of both faces and boundaries without changing any keys. Vertices
+    // need not be modified, because their outgoing arcs are unchanged.
+    for (ab, abc, xa) in arcs {
+        let ba = ab.into_opposite();
+        mutation
+            .as_mut()
+            .connect_adjacent_arcs(ba, xa.into_opposite())?;
+        if let Some(abc) = abc {
+            mutation.as_mut().connect_arc_to_face(ba, abc)?;
+        }
+        else {
+            mutation.as_mut().disconnect_arc_from_face(ba)?;
+        }
+    }
+    for (abc, ab) in faces {
+        mutation
+            .as_mut()
+            .connect_face_to_arc(ab.into_opposite(), abc)?;
+    }
+    Ok(())
+}
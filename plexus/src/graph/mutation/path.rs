@@ -1,17 +1,20 @@
 use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
 
 use crate::entity::borrow::Reborrow;
+use crate::entity::storage::prelude::*;
 use crate::entity::storage::AsStorage;
-use crate::entity::view::Bind;
+use crate::entity::view::{Bind, ClosedView};
 use crate::graph::data::{Data, GraphData, Parametric};
-use crate::graph::edge::Arc;
+use crate::graph::edge::{Arc, ArcKey};
 use crate::graph::face::{Face, FaceKey};
-use crate::graph::mutation::face::{self, FaceInsertCache};
+use crate::graph::mutation::edge::{self, ArcDataCache};
+use crate::graph::mutation::face::{self, FaceInsertCache, FaceRemoveCache};
 use crate::graph::mutation::vertex;
 use crate::graph::mutation::{Consistent, Mode, Mutable, Mutation};
 use crate::graph::path::Path;
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
-use crate::graph::GraphError;
+use crate::graph::{GraphError, OptionExt as _};
 use crate::IteratorExt as _;
 
 pub struct PathExtrudeCache {
@@ -40,6 +43,258 @@ impl PathExtrudeCache {
     }
 }
 
+pub struct PathSplitCache {
+    // Vertices that are copied by the split. Copies are referred to by their
+    // index in this collection.
+    sources: Vec<VertexKey>,
+    // Faces that are reinserted by the split. Each vertex in the perimeter of a
+    // face is paired with the index of its copy, if any.
+    faces: Vec<(FaceRemoveCache, SmallVec<[(VertexKey, Option<usize>); 4]>)>,
+}
+
+impl PathSplitCache {
+    pub fn from_path<B>(path: Path<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let cuts = path
+            .arcs()
+            .keys()
+            .flat_map(|ab| [ab, ab.into_opposite()])
+            .collect::<HashSet<_>>();
+        let mut sources = vec![];
+        let mut copies = HashMap::<(VertexKey, FaceKey), usize>::new();
+        let mut faces = HashMap::new();
+        let mut visited = HashSet::new();
+        for vertex in path.vertices() {
+            if !visited.insert(vertex.key()) {
+                continue;
+            }
+            // Group the faces around the vertex into fans. Faces are in the
+            // same fan if they share an edge that is incident to the vertex and
+            // is not cut by the path.
+            let mut adjacency = HashMap::<FaceKey, Vec<FaceKey>>::new();
+            for arc in vertex.outgoing_arcs() {
+                if cuts.contains(&arc.key()) {
+                    continue;
+                }
+                let opposite = arc.opposite_arc();
+                if let (Some(left), Some(right)) = (arc.face(), opposite.face()) {
+                    adjacency.entry(left.key()).or_default().push(right.key());
+                    adjacency.entry(right.key()).or_default().push(left.key());
+                }
+            }
+            let mut fans = HashSet::new();
+            for (n, face) in vertex.adjacent_faces().enumerate() {
+                let face = face.key();
+                if !fans.insert(face) {
+                    continue;
+                }
+                let mut fan = vec![face];
+                let mut stack = vec![face];
+                while let Some(face) = stack.pop() {
+                    for adjacent in adjacency.get(&face).into_iter().flatten().cloned() {
+                        if fans.insert(adjacent) {
+                            fan.push(adjacent);
+                            stack.push(adjacent);
+                        }
+                    }
+                }
+                // The first fan keeps the vertex. Any other fans are connected
+                // to a copy of the vertex.
+                if n > 0 {
+                    sources.push(vertex.key());
+                    for face in fan {
+                        copies.insert((vertex.key(), face), sources.len() - 1);
+                        faces.insert(
+                            face,
+                            vertex
+                                .into_adjacent_faces()
+                                .find(|adjacent| adjacent.key() == face)
+                                .expect_consistent(),
+                        );
+                    }
+                }
+            }
+        }
+        // Ensure that the faces on either side of each cut are disconnected.
+        // Edges are identified by their vertices, so the split cannot cut an
+        // edge if neither of its vertices is copied.
+        for arc in path.arcs() {
+            if let (Some(left), Some(right)) = (arc.face(), arc.opposite_arc().face()) {
+                let (a, b) = arc.key().into();
+                let is_disjoint = |key: VertexKey| {
+                    copies.get(&(key, left.key())) != copies.get(&(key, right.key()))
+                };
+                if !is_disjoint(a) && !is_disjoint(b) {
+                    return Err(GraphError::TopologyConflict);
+                }
+            }
+        }
+        let faces = faces
+            .into_iter()
+            .map(|(key, face)| {
+                let perimeter = face
+                    .adjacent_vertices()
+                    .keys()
+                    .map(|a| (a, copies.get(&(a, key)).cloned()))
+                    .collect();
+                Ok((FaceRemoveCache::from_face(face)?, perimeter))
+            })
+            .collect::<Result<_, GraphError>>()?;
+        Ok(PathSplitCache { sources, faces })
+    }
+}
+
+pub struct PathSurfaceExtrudeCache {
+    // Vertices along the closed path (in order) that are copied by the
+    // extrusion.
+    sources: Vec<VertexKey>,
+    // Vertices enclosed by the path that are transformed in place.
+    interior: Vec<VertexKey>,
+    faces: Vec<(FaceRemoveCache, SmallVec<[VertexKey; 4]>)>,
+}
+
+impl PathSurfaceExtrudeCache {
+    pub fn from_path<B>(path: Path<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        if path.is_open() {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let cuts = path
+            .arcs()
+            .keys()
+            .flat_map(|ab| [ab, ab.into_opposite()])
+            .collect::<HashSet<_>>();
+        // Collect the faces enclosed by the path, beginning with the faces of
+        // its arcs and without crossing the path.
+        let mut region = HashMap::new();
+        let mut stack = vec![];
+        for arc in path.arcs() {
+            let face = arc.into_face().ok_or(GraphError::TopologyConflict)?;
+            if region.insert(face.key(), face).is_none() {
+                stack.push(face);
+            }
+        }
+        while let Some(face) = stack.pop() {
+            for arc in face.into_adjacent_arcs() {
+                if cuts.contains(&arc.key()) {
+                    continue;
+                }
+                if let Some(face) = arc.into_opposite_arc().into_face() {
+                    if region.insert(face.key(), face).is_none() {
+                        stack.push(face);
+                    }
+                }
+            }
+        }
+        // The path must separate the enclosed faces from the faces on its
+        // opposite side.
+        if path
+            .arcs()
+            .flat_map(|arc| arc.into_opposite_arc().into_face())
+            .any(|face| region.contains_key(&face.key()))
+        {
+            return Err(GraphError::TopologyConflict);
+        }
+        let sources = path
+            .vertices()
+            .keys()
+            .take(path.arcs().len())
+            .collect::<Vec<_>>();
+        let mut interior = HashSet::new();
+        let faces = region
+            .into_iter()
+            .map(|(_, face)| {
+                let perimeter = face.adjacent_vertices().keys().collect::<SmallVec<_>>();
+                interior.extend(perimeter.iter().cloned().filter(|a| !sources.contains(a)));
+                Ok((FaceRemoveCache::from_face(face)?, perimeter))
+            })
+            .collect::<Result<_, GraphError>>()?;
+        Ok(PathSurfaceExtrudeCache {
+            sources,
+            interior: interior.into_iter().collect(),
+            faces,
+        })
+    }
+}
+
+pub fn split<N, P>(mut mutation: N, cache: PathSplitCache) -> Result<(), GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+{
+    let PathSplitCache { sources, faces } = cache;
+    // Copy the data of the arcs and edges along the perimeters of the faces
+    // before they are removed.
+    let arcs = faces
+        .iter()
+        .flat_map(|(_, perimeter)| {
+            perimeter
+                .iter()
+                .map(|(a, _)| *a)
+                .perimeter()
+                .map(ArcKey::from)
+        })
+        .collect::<Vec<_>>();
+    let edges = ArcDataCache::from_arcs(mutation.as_mut(), arcs.iter().cloned())?;
+    // Remove the faces and then any edges that are no longer adjacent to a
+    // face.
+    let faces = faces
+        .into_iter()
+        .map(|(cache, perimeter)| {
+            face::remove(mutation.as_mut(), cache).map(|face| (face.data, perimeter))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    edge::remove_faceless_edges(mutation.as_mut(), arcs)?;
+    // Copy vertices and reinsert the faces using these copies.
+    let copies = sources
+        .into_iter()
+        .map(|a| {
+            let data = VertexView::bind(mutation.as_mut(), a)
+                .ok_or(GraphError::TopologyNotFound)?
+                .get()
+                .clone();
+            Ok(vertex::insert(mutation.as_mut(), data))
+        })
+        .collect::<Result<Vec<_>, GraphError>>()?;
+    for (data, perimeter) in faces {
+        let keys = perimeter
+            .iter()
+            .map(|(a, index)| index.map_or(*a, |index| copies[index]))
+            .collect::<SmallVec<[_; 4]>>();
+        let cache = FaceInsertCache::from_storage(mutation.as_mut(), &keys)?;
+        face::insert_with(mutation.as_mut(), cache, || (Default::default(), data))?;
+        // Restore the data of the arcs and edges of the face. Note that the
+        // opposite arcs of copied vertices are boundary arcs and also receive
+        // the data of their originating arcs.
+        for ((a, b), (c, d)) in perimeter
+            .iter()
+            .map(|(a, _)| *a)
+            .perimeter()
+            .zip(keys.iter().cloned().perimeter())
+        {
+            for (source, destination) in [((a, b), (c, d)), ((b, a), (d, c))] {
+                edges.restore(mutation.as_mut(), source.into(), destination.into())?;
+            }
+        }
+    }
+    Ok(())
+}
+
 pub fn extrude_contour_with<N, P, F>(
     mut mutation: N,
     cache: PathExtrudeCache,
@@ -67,3 +322,98 @@ where
         FaceInsertCache::from_storage(mutation.as_mut(), sources.into_iter().chain(destinations))?;
     face::insert_with(mutation.as_mut(), cache, Default::default)
 }
+
+pub fn extrude_surface_with<N, P, F>(
+    mut mutation: N,
+    cache: PathSurfaceExtrudeCache,
+    f: F,
+) -> Result<Vec<VertexKey>, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+    F: Fn(&<Data<P::Graph> as GraphData>::Vertex) -> <Data<P::Graph> as GraphData>::Vertex,
+{
+    let PathSurfaceExtrudeCache {
+        sources,
+        interior,
+        faces,
+    } = cache;
+    // Copy the data of the arcs and edges of the enclosed faces.
+    let arcs = faces
+        .iter()
+        .flat_map(|(_, perimeter)| perimeter.iter().cloned().perimeter().map(ArcKey::from))
+        .collect::<Vec<_>>();
+    let edges = ArcDataCache::from_arcs(mutation.as_mut(), arcs.iter().cloned())?;
+    // Remove the enclosed faces and then any edges that are no longer adjacent
+    // to a face.
+    let mut faces = faces
+        .into_iter()
+        .map(|(cache, perimeter)| {
+            face::remove(mutation.as_mut(), cache).map(|face| (face.data, perimeter))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    edge::remove_faceless_edges(mutation.as_mut(), arcs)?;
+    // Transform the enclosed vertices and copy the vertices along the path.
+    for a in interior {
+        let data = f(VertexView::bind(mutation.as_mut(), a)
+            .ok_or(GraphError::TopologyNotFound)?
+            .get());
+        mutation.as_mut().replace_vertex_data(a, data)?;
+    }
+    let mut copies = HashMap::with_capacity(sources.len());
+    for a in sources.iter().cloned() {
+        let data = f(VertexView::bind(mutation.as_mut(), a)
+            .ok_or(GraphError::TopologyNotFound)?
+            .get());
+        copies.insert(a, vertex::insert(mutation.as_mut(), data));
+    }
+    let copy = |a: VertexKey| copies.get(&a).cloned().unwrap_or(a);
+    // Reinsert the faces using the copied vertices. Faces are inserted such
+    // that they are adjacent to existing faces when possible, which avoids
+    // pinching vertices while rebuilding.
+    while !faces.is_empty() {
+        let index = {
+            let core = mutation.as_mut().to_ref_core();
+            faces
+                .iter()
+                .position(|(_, perimeter)| {
+                    perimeter
+                        .iter()
+                        .cloned()
+                        .map(copy)
+                        .perimeter()
+                        .any(|(a, b)| {
+                            core.arcs
+                                .get(&(b, a).into())
+                                .map_or(false, |arc| arc.face.is_some())
+                        })
+                })
+                .unwrap_or(0)
+        };
+        let (data, perimeter) = faces.remove(index);
+        let keys = perimeter
+            .iter()
+            .cloned()
+            .map(copy)
+            .collect::<SmallVec<[_; 4]>>();
+        let cache = FaceInsertCache::from_storage(mutation.as_mut(), &keys)?;
+        face::insert_with(mutation.as_mut(), cache, || (Default::default(), data))?;
+        for ((a, b), (c, d)) in perimeter
+            .iter()
+            .cloned()
+            .perimeter()
+            .zip(keys.iter().cloned().perimeter())
+        {
+            for (source, destination) in [((a, b), (c, d)), ((b, a), (d, c))] {
+                edges.restore(mutation.as_mut(), source.into(), destination.into())?;
+            }
+        }
+    }
+    // Stitch the path to its extrusion with connective faces.
+    for (a, b) in sources.iter().cloned().perimeter() {
+        let cache = FaceInsertCache::from_storage(mutation.as_mut(), &[a, b, copy(b), copy(a)])?;
+        face::insert_with(mutation.as_mut(), cache, Default::default)?;
+    }
+    Ok(sources.into_iter().map(copy).collect())
+}
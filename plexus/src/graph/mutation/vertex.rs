@@ -1,14 +1,19 @@
+use std::mem;
+
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::prelude::*;
 use crate::entity::storage::{AsStorage, AsStorageMut, Fuse, StorageTarget};
 use crate::graph::core::Core;
 use crate::graph::data::{Data, GraphData, Parametric};
-use crate::graph::edge::ArcKey;
-use crate::graph::mutation::edge::{self, EdgeRemoveCache};
+use crate::graph::edge::{Arc, ArcKey};
+use crate::graph::face::Face;
+use crate::graph::mutation::edge;
+use crate::graph::mutation::face::{self, FaceRemoveCache};
 use crate::graph::mutation::{Consistent, Immediate, Mode, Mutable, Mutation};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
 use crate::graph::GraphError;
 use crate::transact::{Bypass, Transact};
+use crate::IteratorExt as _;
 
 type ModalCore<P> = Core<Data<<P as Mode>::Graph>, <P as Mode>::VertexStorage, (), (), ()>;
 #[cfg(not(all(nightly, feature = "unstable")))]
@@ -35,12 +40,23 @@ where
         self.with_vertex_mut(a, |vertex| vertex.arc = Some(ab))
     }
 
-    // TODO: See `edge::split_with_cache`.
-    #[allow(dead_code)]
     pub fn disconnect_outgoing_arc(&mut self, a: VertexKey) -> Result<Option<ArcKey>, GraphError> {
         self.with_vertex_mut(a, |vertex| vertex.arc.take())
     }
 
+    pub fn replace_vertex_data(
+        &mut self,
+        a: VertexKey,
+        data: <Data<P::Graph> as GraphData>::Vertex,
+    ) -> Result<<Data<P::Graph> as GraphData>::Vertex, GraphError> {
+        let vertex = self
+            .storage
+            .as_storage_mut()
+            .get_mut(&a)
+            .ok_or(GraphError::TopologyNotFound)?;
+        Ok(mem::replace(&mut vertex.data, data))
+    }
+
     fn with_vertex_mut<T, F>(&mut self, a: VertexKey, mut f: F) -> Result<T, GraphError>
     where
         F: FnMut(&mut Vertex<Data<P::Graph>>) -> T,
@@ -110,17 +126,30 @@ where
 }
 
 pub struct VertexRemoveCache {
-    cache: Vec<EdgeRemoveCache>,
+    a: VertexKey,
+    arcs: Vec<ArcKey>,
+    faces: Vec<FaceRemoveCache>,
 }
 
 impl VertexRemoveCache {
     pub fn from_vertex<B>(vertex: VertexView<B>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Vertex<Data<B>>> + Consistent + Parametric,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
     {
-        let _ = vertex;
-        unimplemented!()
+        let faces = vertex
+            .adjacent_faces()
+            .map(FaceRemoveCache::from_face)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(VertexRemoveCache {
+            a: vertex.key(),
+            arcs: vertex.outgoing_arcs().keys().collect(),
+            faces,
+        })
     }
 }
 
@@ -146,9 +175,52 @@ where
     P: Mode,
     P::Graph: Mutable,
 {
-    let VertexRemoveCache { cache } = cache;
-    for cache in cache {
-        edge::remove(mutation.as_mut(), cache)?;
+    let VertexRemoveCache { a, arcs, faces } = cache;
+    // Remove faces first, so that the edges connected to the vertex are no
+    // longer adjacent to any faces and can be removed while joining the
+    // remaining arcs into boundaries.
+    for cache in faces {
+        face::remove(mutation.as_mut(), cache)?;
+    }
+    for ab in arcs {
+        let (_, b) = ab.into();
+        edge::remove_faceless(mutation.as_mut(), ab)?;
+        // Remove any vertices that no longer have a leading arc.
+        let is_disjoint = mutation
+            .as_mut()
+            .storage
+            .as_storage()
+            .get(&b)
+            .ok_or(GraphError::TopologyMalformed)?
+            .arc
+            .is_none();
+        if is_disjoint {
+            remove_disjoint(mutation.as_mut(), b)?;
+        }
+    }
+    remove_disjoint(mutation.as_mut(), a)
+}
+
+/// Removes a vertex that has no leading arc.
+///
+/// Fails with `TopologyConflict` if any arc is still connected to the vertex.
+pub fn remove_disjoint<N, P>(
+    mut mutation: N,
+    a: VertexKey,
+) -> Result<Vertex<Data<P::Graph>>, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+{
+    let storage = mutation.as_mut().storage.as_storage_mut();
+    if storage
+        .get(&a)
+        .ok_or(GraphError::TopologyNotFound)?
+        .arc
+        .is_some()
+    {
+        return Err(GraphError::TopologyConflict);
     }
-    unimplemented!()
+    storage.remove(&a).ok_or(GraphError::TopologyNotFound)
 }
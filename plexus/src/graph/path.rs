@@ -10,7 +10,7 @@ use crate::geometry::Metric;
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcView, Edge};
 use crate::graph::face::{Face, FaceView, Ring};
-use crate::graph::mutation::path::{self, PathExtrudeCache};
+use crate::graph::mutation::path::{self, PathExtrudeCache, PathSurfaceExtrudeCache};
 use crate::graph::mutation::{self, Consistent, Immediate, Mutable};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
 use crate::graph::{GraphError, OptionExt as _, ResultExt as _, Selector};
@@ -96,6 +96,11 @@ where
         }
     }
 
+    pub(in crate::graph) fn into_storage(self) -> B {
+        let Path { storage, .. } = self;
+        storage
+    }
+
     /// Converts the path into its opposite path.
     pub fn into_opposite_path(self) -> Path<'static, B> {
         let Path { keys, storage } = self;
@@ -494,12 +499,67 @@ where
     }
 
     /// Extrudes the surface of a closed path.
+    ///
+    /// The surface of a closed path consists of the faces that are enclosed by
+    /// the path, beginning with the faces of its arcs. Extruding the surface
+    /// transforms the vertices of these faces using the given function. The
+    /// vertices along the path are copied and the path is connected to its
+    /// extruded copy with quadrilateral faces.
+    ///
+    /// Returns the extruded path, which is formed from the copies of the
+    /// vertices along the path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is open, if any arc in the path has no
+    /// face, or if the path does not separate the enclosed faces from the
+    /// faces on its opposite side.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    ///
+    /// let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+    ///     vec![Tetragon::new(0usize, 1, 2, 3)],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (1.0, 0.0, 0.0),
+    ///         (1.0, 1.0, 0.0),
+    ///         (0.0, 1.0, 0.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// let path = graph.face_mut(key).unwrap().into_ring().into_path();
+    /// let path = path
+    ///     .extrude_surface_with(|position| position + Vector3::z())
+    ///     .unwrap();
+    ///
+    /// assert!(path.is_closed());
+    /// assert_eq!(8, graph.vertex_count());
+    /// assert_eq!(5, graph.face_count());
+    /// ```
     pub fn extrude_surface_with<F>(self, f: F) -> Result<Self, GraphError>
     where
         F: Fn(G::Vertex) -> G::Vertex,
     {
-        let _ = f;
-        todo!()
+        let cache = PathSurfaceExtrudeCache::from_path(self.to_ref())?;
+        let Path { storage, .. } = self;
+        let (storage, keys) = Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| {
+                path::extrude_surface_with(mutation, cache, |data| f(data.clone()))
+            })
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        let front = keys.first().cloned();
+        Ok(Path::bind(storage, keys.into_iter().chain(front)).expect_consistent())
     }
 }
 
@@ -545,9 +605,9 @@ where
 
 #[cfg(test)]
 mod tests {
-    use nalgebra::Point2;
+    use nalgebra::{Point2, Point3, Vector3};
 
-    use crate::graph::{ClosedView, MeshGraph};
+    use crate::graph::{ClosedView, GraphError, MeshGraph};
     use crate::prelude::*;
     use crate::primitive::{Tetragon, Trigon};
     use crate::IteratorExt;
@@ -607,6 +667,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extrude_surface() {
+        // Construct a graph from a 2x2 grid of quadrilaterals.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![
+                Tetragon::new(0usize, 1, 4, 3),
+                Tetragon::new(1, 2, 5, 4),
+                Tetragon::new(3, 4, 7, 6),
+                Tetragon::new(4, 5, 8, 7),
+            ],
+            (0..3)
+                .flat_map(|y| (0..3).map(move |x| (x as f64, y as f64, 0.0)))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        // Extrude the surface enclosed by the boundary of the grid.
+        let key = graph
+            .arcs()
+            .find(|arc| arc.is_boundary_arc())
+            .unwrap()
+            .key();
+        let path = graph
+            .arc_mut(key)
+            .unwrap()
+            .into_ring()
+            .into_path()
+            .into_opposite_path()
+            .extrude_surface_with(|position| position + Vector3::z())
+            .unwrap()
+            .into_ref();
+
+        assert!(path.is_closed());
+        assert_eq!(8, path.arcs().count());
+        assert!(path.vertices().all(|vertex| vertex.get().z == 1.0));
+        assert_eq!(17, graph.vertex_count());
+        assert_eq!(12, graph.face_count());
+        // The interior vertex is transformed but not copied.
+        assert_eq!(
+            1,
+            graph
+                .vertices()
+                .filter(|vertex| vertex.get().x == 1.0 && vertex.get().y == 1.0)
+                .count()
+        );
+    }
+
+    #[test]
+    fn extrude_surface_of_open_path() {
+        let mut graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![Trigon::from([0usize, 1, 2])],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)],
+        )
+        .unwrap();
+        let keys = graph.vertices().keys().take(2).collect::<Vec<_>>();
+        let path = graph.path_mut(keys.iter()).unwrap();
+
+        assert!(path.is_open());
+        assert!(matches!(
+            path.extrude_surface_with(|position| position),
+            Err(GraphError::TopologyMalformed)
+        ));
+        assert_eq!(1, graph.face_count());
+    }
+
     #[test]
     fn split() {
         let graph =
@@ -0,0 +1,545 @@
+//! Isotropic remeshing.
+
+use decorum::Real;
+use num::{NumCast, One, Zero};
+use std::collections::HashMap;
+use std::f64::consts::FRAC_PI_4;
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use theon::{AsPosition, AsPositionMut};
+use typenum::U3;
+
+use crate::graph::data::GraphData;
+use crate::graph::decimation;
+use crate::graph::edge::ArcView;
+use crate::graph::geometry::{EdgeMidpoint, FaceNormal, VertexPosition};
+use crate::graph::vertex::VertexView;
+use crate::graph::{ByKey, GraphError, MeshGraph, OptionExt as _, ResultExt as _};
+use crate::{DynamicArity, IteratorExt as _};
+
+// Splitting halves long edges in each pass, so this bounds the length of edges
+// relative to the target length after a single iteration. Edges that remain too
+// long are split again in subsequent iterations.
+const SPLIT_PASS_LIMIT: usize = 16;
+
+type Cell = (i64, i64, i64);
+
+// Triangles of a reference surface onto which points are projected. Triangles
+// are bucketed into a grid of cubic cells that they overlap.
+struct Surface<S>
+where
+    S: EuclideanSpace,
+{
+    triangles: Vec<[S; 3]>,
+    grid: HashMap<Cell, Vec<usize>>,
+    size: Scalar<S>,
+    bounds: (Cell, Cell),
+}
+
+impl<S> Surface<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>> + InnerSpace,
+    Scalar<S>: NumCast,
+{
+    fn from_triangles(triangles: impl IntoIterator<Item = [S; 3]>, size: Scalar<S>) -> Self {
+        // Degenerate triangles have no closest points and are discarded.
+        let triangles = triangles
+            .into_iter()
+            .filter(|[a, b, c]| (*b - *a).cross(*c - *a).magnitude() > Zero::zero())
+            .collect::<Vec<_>>();
+        let mut grid = HashMap::<_, Vec<_>>::new();
+        let mut bounds: Option<(Cell, Cell)> = None;
+        for (index, triangle) in triangles.iter().enumerate() {
+            let cells = triangle.iter().map(|point| locate(*point, size));
+            let lower = cells
+                .clone()
+                .reduce(|(ax, ay, az), (bx, by, bz)| (ax.min(bx), ay.min(by), az.min(bz)))
+                .expect_consistent();
+            let upper = cells
+                .reduce(|(ax, ay, az), (bx, by, bz)| (ax.max(bx), ay.max(by), az.max(bz)))
+                .expect_consistent();
+            for x in lower.0..=upper.0 {
+                for y in lower.1..=upper.1 {
+                    for z in lower.2..=upper.2 {
+                        grid.entry((x, y, z)).or_default().push(index);
+                    }
+                }
+            }
+            bounds = Some(match bounds {
+                Some((min, max)) => (
+                    (min.0.min(lower.0), min.1.min(lower.1), min.2.min(lower.2)),
+                    (max.0.max(upper.0), max.1.max(upper.1), max.2.max(upper.2)),
+                ),
+                _ => (lower, upper),
+            });
+        }
+        Surface {
+            triangles,
+            grid,
+            size,
+            bounds: bounds.unwrap_or_default(),
+        }
+    }
+
+    // Gets the closest point on the surface to the given point. Cells are
+    // searched in shells of increasing radius until no closer triangle can be
+    // found.
+    fn project(&self, point: S) -> S {
+        let (x, y, z) = locate(point, self.size);
+        let ((x0, y0, z0), (x1, y1, z1)) = self.bounds;
+        let extent = [x - x0, x1 - x, y - y0, y1 - y, z - z0, z1 - z]
+            .iter()
+            .cloned()
+            .max()
+            .unwrap_or(0);
+        let mut closest: Option<(Scalar<S>, S)> = None;
+        for radius in 0..=extent.max(0) {
+            for dx in -radius..=radius {
+                for dy in -radius..=radius {
+                    for dz in -radius..=radius {
+                        if dx.abs().max(dy.abs()).max(dz.abs()) != radius {
+                            continue;
+                        }
+                        for index in self
+                            .grid
+                            .get(&(x + dx, y + dy, z + dz))
+                            .into_iter()
+                            .flatten()
+                        {
+                            let candidate = closest_point(point, self.triangles[*index]);
+                            let distance = (candidate - point).magnitude();
+                            if closest.map_or(true, |(minimum, _)| distance < minimum) {
+                                closest = Some((distance, candidate));
+                            }
+                        }
+                    }
+                }
+            }
+            // Triangles in cells beyond this radius are farther from the point
+            // than the size of the cells times the radius.
+            let reach = <Scalar<S> as NumCast>::from(radius).unwrap() * self.size;
+            if closest.map_or(false, |(minimum, _)| minimum <= reach) {
+                break;
+            }
+        }
+        closest.map_or(point, |(_, point)| point)
+    }
+}
+
+/// Remeshes a graph such that its edges approach the given length.
+///
+/// Returns an error if the target length is not positive and finite or if the
+/// graph cannot be triangulated.
+pub fn remesh_isotropic<G>(
+    graph: &mut MeshGraph<G>,
+    target: Scalar<VertexPosition<G>>,
+    iterations: usize,
+) -> Result<(), GraphError>
+where
+    G: EdgeMidpoint + FaceNormal,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+    Scalar<VertexPosition<G>>: NumCast,
+{
+    if !target
+        .to_f64()
+        .map_or(false, |target| target.is_finite() && target > 0.0)
+    {
+        return Err(GraphError::Geometry);
+    }
+    graph.try_triangulate()?;
+    let surface = Surface::from_triangles(
+        graph.faces().map(|face| {
+            face.adjacent_vertices()
+                .map(|vertex| *vertex.position())
+                .try_collect::<[_; 3]>()
+                .expect_consistent()
+        }),
+        target,
+    );
+    let ratio = |numerator: u8, denominator: u8| {
+        <Scalar<VertexPosition<G>> as NumCast>::from(f64::from(numerator) / f64::from(denominator))
+            .unwrap()
+    };
+    let (low, high) = (target * ratio(4, 5), target * ratio(4, 3));
+    for _ in 0..iterations {
+        split(graph, high);
+        collapse(graph, low, high);
+        flip(graph);
+        relax(graph, &surface);
+    }
+    Ok(())
+}
+
+// Splits edges that are longer than the given length at their midpoints and
+// then splits the adjacent faces such that the graph remains triangular. At
+// most `SPLIT_PASS_LIMIT` passes are made over the edges.
+fn split<G>(graph: &mut MeshGraph<G>, high: Scalar<VertexPosition<G>>)
+where
+    G: EdgeMidpoint + FaceNormal,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: InnerSpace,
+{
+    for _ in 0..SPLIT_PASS_LIMIT {
+        let keys = graph
+            .edges()
+            .filter(|edge| length(edge.arc()) > high)
+            .map(|edge| edge.arc().key())
+            .collect::<Vec<_>>();
+        if keys.is_empty() {
+            break;
+        }
+        for ab in keys {
+            let m = graph
+                .arc_mut(ab)
+                .expect_consistent()
+                .split_at_midpoint()
+                .key();
+            let faces = graph
+                .vertex(m)
+                .expect_consistent()
+                .adjacent_faces()
+                .keys()
+                .collect::<Vec<_>>();
+            for abc in faces {
+                let face = graph.face(abc).expect_consistent();
+                if face.arity() != 4 {
+                    continue;
+                }
+                let keys = face.adjacent_vertices().keys().collect::<Vec<_>>();
+                let index = keys.iter().position(|key| *key == m).expect_consistent();
+                let c = keys[(index + 2) % keys.len()];
+                graph
+                    .face_mut(abc)
+                    .expect_consistent()
+                    .split(ByKey(m), ByKey(c))
+                    .expect_consistent();
+            }
+        }
+    }
+}
+
+// Collapses edges that are shorter than the given length into one of their
+// vertices. Collapses that would move constrained vertices, create edges longer
+// than the given upper length, or flip the normals of faces are rejected.
+fn collapse<G>(
+    graph: &mut MeshGraph<G>,
+    low: Scalar<VertexPosition<G>>,
+    high: Scalar<VertexPosition<G>>,
+) where
+    G: FaceNormal,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+    Scalar<VertexPosition<G>>: NumCast,
+{
+    let keys = graph
+        .edges()
+        .filter(|edge| length(edge.arc()) < low)
+        .map(|edge| edge.arc().key())
+        .collect::<Vec<_>>();
+    for ab in keys {
+        let ab = match graph.arc(ab) {
+            Some(arc) if length(arc) < low => {
+                match (
+                    is_constrained_vertex(arc.source_vertex()),
+                    is_constrained_vertex(arc.destination_vertex()),
+                ) {
+                    (false, _) => arc,
+                    (true, false) => arc.into_opposite_arc(),
+                    (true, true) => continue,
+                }
+            }
+            _ => continue,
+        };
+        let position = *ab.destination_vertex().position();
+        if ab
+            .source_vertex()
+            .adjacent_vertices()
+            .any(|vertex| (*vertex.position() - position).magnitude() > high)
+        {
+            continue;
+        }
+        let ab = ab.key();
+        if decimation::is_folding(graph, ab, position) {
+            continue;
+        }
+        // Collapses that would create non-manifold topology are rejected.
+        let _ = graph
+            .arc_mut(ab)
+            .expect_consistent()
+            .collapse_with(|_, destination| destination.clone());
+    }
+}
+
+// Flips edges between triangles if doing so reduces the deviation of the
+// valence of their vertices from the ideal valence (six for interior vertices
+// and four for boundary vertices).
+fn flip<G>(graph: &mut MeshGraph<G>)
+where
+    G: FaceNormal,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+    Scalar<VertexPosition<G>>: NumCast,
+{
+    let keys = graph
+        .edges()
+        .map(|edge| edge.arc().key())
+        .collect::<Vec<_>>();
+    for ab in keys {
+        let arc = match graph.arc(ab) {
+            Some(arc) if !is_constrained_edge(arc) => arc,
+            _ => continue,
+        };
+        let ba = arc.opposite_arc();
+        let (abc, bad) = match (arc.face(), ba.face()) {
+            (Some(abc), Some(bad)) if abc.arity() == 3 && bad.arity() == 3 => (abc, bad),
+            _ => continue,
+        };
+        let vertices = [
+            arc.into_source_vertex(),
+            arc.into_destination_vertex(),
+            arc.into_next_arc().into_destination_vertex(),
+            ba.into_next_arc().into_destination_vertex(),
+        ];
+        let deviation = |offsets: [isize; 4]| {
+            vertices
+                .iter()
+                .zip(offsets.iter())
+                .map(|(vertex, offset)| {
+                    let ideal = if vertex.is_boundary_vertex() { 4 } else { 6 };
+                    ((vertex.valence() as isize) + offset - ideal).abs()
+                })
+                .sum::<isize>()
+        };
+        if deviation([-1, -1, 1, 1]) >= deviation([0, 0, 0, 0]) {
+            continue;
+        }
+        // The inserted triangles must face the same direction as the removed
+        // triangles, which is not the case if the quadrilateral formed by the
+        // triangles is not convex.
+        let [a, b, c, d] = vertices.map(|vertex| *vertex.position());
+        let normal = match (abc.normal(), bad.normal()) {
+            (Ok(abc), Ok(bad)) => abc + bad,
+            _ => continue,
+        };
+        if (a - c).cross(d - c).dot(normal) <= Zero::zero()
+            || (b - d).cross(c - d).dot(normal) <= Zero::zero()
+        {
+            continue;
+        }
+        // Flipping fails if the vertices opposite the edge are already
+        // connected, in which case the edge is kept.
+        let _ = graph.arc_mut(ab).expect_consistent().flip();
+    }
+}
+
+// Moves unconstrained vertices toward the centroid of their neighbors within
+// their tangent planes and then projects them onto the reference surface.
+fn relax<G>(graph: &mut MeshGraph<G>, surface: &Surface<VertexPosition<G>>)
+where
+    G: FaceNormal,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+    Scalar<VertexPosition<G>>: NumCast,
+{
+    let positions = graph
+        .vertices()
+        .filter(|vertex| !is_constrained_vertex(*vertex))
+        .flat_map(|vertex| {
+            let position = *vertex.position();
+            let normal = vertex.normal().ok()?;
+            let offset = vertex.centroid() - position;
+            let offset = offset - (normal * normal.dot(offset));
+            Some((vertex.key(), surface.project(position + offset)))
+        })
+        .collect::<Vec<_>>();
+    for (key, position) in positions {
+        *graph
+            .vertex_mut(key)
+            .expect_consistent()
+            .get_mut()
+            .as_position_mut() = position;
+    }
+}
+
+fn length<G>(arc: ArcView<&MeshGraph<G>>) -> Scalar<VertexPosition<G>>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: InnerSpace,
+{
+    (*arc.destination_vertex().position() - *arc.source_vertex().position()).magnitude()
+}
+
+// Determines if the vertex is connected to any boundary or feature edges.
+fn is_constrained_vertex<G>(vertex: VertexView<&MeshGraph<G>>) -> bool
+where
+    G: FaceNormal,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: InnerSpace,
+    Scalar<VertexPosition<G>>: NumCast,
+{
+    vertex.outgoing_arcs().any(is_constrained_edge)
+}
+
+// Determines if the edge of the arc is a boundary edge or a feature edge. Edges
+// are features if the angle between the normals of their adjacent faces exceeds
+// 45 degrees or if either normal cannot be computed.
+fn is_constrained_edge<G>(arc: ArcView<&MeshGraph<G>>) -> bool
+where
+    G: FaceNormal,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: InnerSpace,
+    Scalar<VertexPosition<G>>: NumCast,
+{
+    let cosine = <Scalar<VertexPosition<G>> as NumCast>::from(FRAC_PI_4)
+        .unwrap()
+        .cos();
+    match (arc.face(), arc.opposite_arc().face()) {
+        (Some(ab), Some(ba)) => match (ab.normal(), ba.normal()) {
+            (Ok(ab), Ok(ba)) => ab.dot(ba) < cosine,
+            _ => true,
+        },
+        _ => true,
+    }
+}
+
+fn locate<S>(point: S, size: Scalar<S>) -> Cell
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Scalar<S>: NumCast,
+{
+    let (x, y, z) = point.into_xyz();
+    let index = |x: Scalar<S>| (x / size).floor().to_i64().unwrap_or(0);
+    (index(x), index(y), index(z))
+}
+
+// Gets the closest point on a triangle to the given point. See Ericson,
+// _Real-Time Collision Detection_, section 5.1.5.
+fn closest_point<S>(p: S, [a, b, c]: [S; 3]) -> S
+where
+    S: EuclideanSpace,
+    Vector<S>: InnerSpace,
+{
+    let zero = Zero::zero();
+    let (ab, ac, ap) = (b - a, c - a, p - a);
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= zero && d2 <= zero {
+        return a;
+    }
+    let bp = p - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= zero && d4 <= d3 {
+        return b;
+    }
+    let vc = (d1 * d4) - (d3 * d2);
+    if vc <= zero && d1 >= zero && d3 <= zero {
+        return a + (ab * (d1 / (d1 - d3)));
+    }
+    let cp = p - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= zero && d5 <= d6 {
+        return c;
+    }
+    let vb = (d5 * d2) - (d1 * d6);
+    if vb <= zero && d2 >= zero && d6 <= zero {
+        return a + (ac * (d2 / (d2 - d6)));
+    }
+    let va = (d3 * d6) - (d5 * d4);
+    if va <= zero && (d4 - d3) >= zero && (d5 - d6) >= zero {
+        return b + ((c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6))));
+    }
+    let denominator = <Scalar<S> as One>::one() / (va + vb + vc);
+    a + (ab * (vb * denominator)) + (ac * (vc * denominator))
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::tests::grid;
+    use crate::graph::{GraphError, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn remesh_sphere() {
+        let mut graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+        graph.remesh_isotropic(0.25, 4).unwrap();
+
+        assert!(graph.validate().is_empty());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        let lengths = graph
+            .edges()
+            .map(|edge| {
+                let arc = edge.arc();
+                (arc.destination_vertex().position() - arc.source_vertex().position()).magnitude()
+            })
+            .collect::<Vec<_>>();
+        let mean = lengths.iter().sum::<f64>() / (lengths.len() as f64);
+        assert!(mean > 0.2 && mean < 0.3);
+        // Vertices are projected onto the original surface, which is inscribed
+        // in the unit sphere.
+        for vertex in graph.vertices() {
+            let radius = vertex.position().coords.magnitude();
+            assert!(radius > 0.9 && radius < 1.0 + 1e-6);
+        }
+    }
+
+    #[test]
+    fn remesh_plane_with_preserved_boundary() {
+        let mut graph = grid(5);
+        let boundary = |graph: &MeshGraph<Point3<f64>>| {
+            graph
+                .boundaries()
+                .nth(0)
+                .unwrap()
+                .vertices()
+                .map(|vertex| (vertex.position().x, vertex.position().y))
+                .collect::<Vec<_>>()
+        };
+        let expected = boundary(&graph);
+        graph.remesh_isotropic(0.5, 3).unwrap();
+
+        assert!(graph.validate().is_empty());
+        assert!(graph.face_count() > 32);
+        assert!(graph.vertices().all(|vertex| vertex.position().z == 0.0));
+        // Boundary edges are split, but boundary vertices are never moved.
+        let positions = boundary(&graph);
+        assert!(expected.iter().all(|position| positions.contains(position)));
+        assert!(positions
+            .iter()
+            .all(|(x, y)| [0.0, 4.0].contains(x) || [0.0, 4.0].contains(y)));
+    }
+
+    #[test]
+    fn remesh_with_invalid_target() {
+        let mut graph = grid(3);
+
+        assert_eq!(Err(GraphError::Geometry), graph.remesh_isotropic(0.0, 1));
+        assert_eq!(Err(GraphError::Geometry), graph.remesh_isotropic(-1.0, 1));
+        assert_eq!(
+            Err(GraphError::Geometry),
+            graph.remesh_isotropic(f64::INFINITY, 1)
+        );
+        assert_eq!(
+            Err(GraphError::Geometry),
+            graph.remesh_isotropic(f64::NAN, 1)
+        );
+        assert_eq!(8, graph.face_count());
+    }
+}
@@ -0,0 +1,403 @@
+//! Repair of non-manifold polygonal input.
+
+use smallvec::SmallVec;
+use std::cmp;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::graph::data::GraphData;
+use crate::graph::mutation::face::{self, FaceInsertCache};
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::{GraphError, MeshGraph};
+use crate::transact::Transact;
+use crate::IteratorExt as _;
+
+type Mutation<G> = mutation::Mutation<Immediate<MeshGraph<G>>>;
+
+/// Repair applied when importing polygonal input into a [`MeshGraph`].
+///
+/// Faces and vertices are identified by their indices in the input. See
+/// [`MeshGraph::from_raw_buffers_with_repair`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::from_raw_buffers_with_repair`]: crate::graph::MeshGraph::from_raw_buffers_with_repair
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Repair {
+    /// A face has been dropped, because it has fewer than three vertices or
+    /// visits a vertex more than once.
+    DroppedDegenerateFace { face: usize },
+    /// A face has been dropped, because its winding cannot agree with all of
+    /// its adjacent faces, such as in a Möbius strip.
+    DroppedInconsistentFace { face: usize },
+    /// A face has been dropped, because it conflicts with topology that
+    /// remains after all other repairs, such as a duplicate face.
+    DroppedConflictingFace { face: usize },
+    /// A face has been detached from an edge that is shared by more than two
+    /// faces. The first two faces that share the edge remain adjacent.
+    DetachedFace { face: usize, edge: (usize, usize) },
+    /// A face has been reversed to agree with the winding of its adjacent
+    /// faces.
+    ReversedFace { face: usize },
+    /// A vertex has been duplicated, because it joins fans of faces that are
+    /// otherwise disjoint (a _bowtie_). Each fan beyond the first is given its
+    /// own copy of the vertex.
+    DuplicatedVertex { vertex: usize },
+    /// A vertex has been dropped, because it is not referenced by any face
+    /// that remains after all other repairs.
+    DroppedUnusedVertex { vertex: usize },
+}
+
+/// Constructs a graph from vertex data and faces given as indices into that
+/// data, repairing non-manifold topology.
+///
+/// Returns an error if an index is out of bounds.
+pub fn from_faces_with_repair<G>(
+    vertices: Vec<G::Vertex>,
+    faces: Vec<SmallVec<[usize; 4]>>,
+) -> Result<(MeshGraph<G>, Vec<Repair>), GraphError>
+where
+    G: GraphData,
+{
+    let n = vertices.len();
+    if faces.iter().flatten().any(|&index| index >= n) {
+        return Err(GraphError::TopologyNotFound);
+    }
+    let mut repairs = vec![];
+    let mut faces = faces
+        .into_iter()
+        .enumerate()
+        .map(|(index, perimeter)| {
+            let set = perimeter.iter().collect::<HashSet<_>>();
+            if perimeter.len() < 3 || set.len() != perimeter.len() {
+                repairs.push(Repair::DroppedDegenerateFace { face: index });
+                None
+            }
+            else {
+                Some(perimeter)
+            }
+        })
+        .collect::<Vec<_>>();
+    let adjacency = adjacency(&faces, &mut repairs);
+    orient(&mut faces, &adjacency, &mut repairs);
+    let sources = separate(n, &mut faces, &adjacency, &mut repairs);
+
+    let mut mutation = Mutation::from(MeshGraph::new());
+    // Vertices are inserted when they are first referenced by a face, so
+    // vertices that are not referenced by any remaining face are dropped.
+    let mut keys = vec![None; n + sources.len()];
+    for (index, perimeter) in faces.into_iter().enumerate() {
+        if let Some(perimeter) = perimeter {
+            let mut inserted = SmallVec::<[_; 4]>::new();
+            let perimeter = perimeter
+                .into_iter()
+                .map(|vertex| {
+                    *keys[vertex].get_or_insert_with(|| {
+                        let source = if vertex < n {
+                            vertex
+                        }
+                        else {
+                            sources[vertex - n]
+                        };
+                        inserted.push(vertex);
+                        mutation::vertex::insert(&mut mutation, vertices[source].clone())
+                    })
+                })
+                .collect::<SmallVec<[_; 4]>>();
+            match FaceInsertCache::from_storage(&mutation, &perimeter) {
+                Ok(cache) => {
+                    face::insert_with(&mut mutation, cache, Default::default)?;
+                }
+                Err(_) => {
+                    // Remove any vertices that have only been inserted for
+                    // this face.
+                    for vertex in inserted {
+                        if let Some(key) = keys[vertex].take() {
+                            mutation::vertex::remove_disjoint(&mut mutation, key)?;
+                        }
+                    }
+                    repairs.push(Repair::DroppedConflictingFace { face: index });
+                }
+            }
+        }
+    }
+    repairs.extend(
+        keys.iter()
+            .take(n)
+            .enumerate()
+            .filter(|(_, key)| key.is_none())
+            .map(|(vertex, _)| Repair::DroppedUnusedVertex { vertex }),
+    );
+    mutation
+        .commit()
+        .map(|graph| (graph, repairs))
+        .map_err(|(_, error)| error)
+}
+
+// Gets the faces that are adjacent to each face along with the (unordered)
+// edge that they share. Faces beyond the first two that share an edge are
+// detached from that edge.
+fn adjacency(
+    faces: &[Option<SmallVec<[usize; 4]>>],
+    repairs: &mut Vec<Repair>,
+) -> Vec<Vec<(usize, (usize, usize))>> {
+    let mut edges = HashMap::<_, Vec<_>>::new();
+    for (index, perimeter) in faces.iter().enumerate() {
+        if let Some(perimeter) = perimeter {
+            for (a, b) in perimeter.iter().cloned().perimeter() {
+                edges.entry(edge(a, b)).or_default().push(index);
+            }
+        }
+    }
+    let mut adjacency = vec![vec![]; faces.len()];
+    let mut edges = edges.into_iter().collect::<Vec<_>>();
+    edges.sort_unstable();
+    for (edge, indices) in edges {
+        if let [a, b, ref detached @ ..] = indices[..] {
+            adjacency[a].push((b, edge));
+            adjacency[b].push((a, edge));
+            for &face in detached {
+                repairs.push(Repair::DetachedFace { face, edge });
+            }
+        }
+    }
+    adjacency
+}
+
+// Reverses faces to agree with the winding of their adjacent faces. Faces are
+// visited in breadth-first order from the first face in each connected
+// component. A face that cannot agree with all of its visited adjacent faces is
+// dropped.
+fn orient(
+    faces: &mut [Option<SmallVec<[usize; 4]>>],
+    adjacency: &[Vec<(usize, (usize, usize))>],
+    repairs: &mut Vec<Repair>,
+) {
+    let mut is_oriented = vec![false; faces.len()];
+    let mut is_visited = vec![false; faces.len()];
+    for seed in 0..faces.len() {
+        if faces[seed].is_none() || is_visited[seed] {
+            continue;
+        }
+        is_visited[seed] = true;
+        let mut queue = VecDeque::from(vec![seed]);
+        while let Some(index) = queue.pop_front() {
+            // Adjacent faces agree if they traverse their shared edge in
+            // opposite directions, so each oriented adjacent face determines
+            // whether or not this face must be reversed.
+            let constraints = adjacency[index]
+                .iter()
+                .filter(|(adjacent, _)| is_oriented[*adjacent])
+                .filter_map(|&(adjacent, (a, b))| {
+                    let perimeter = faces[index].as_ref()?;
+                    let adjacent = faces[adjacent].as_ref()?;
+                    Some(is_forward(perimeter, a, b) == is_forward(adjacent, a, b))
+                })
+                .collect::<HashSet<_>>();
+            if constraints.len() > 1 {
+                faces[index] = None;
+                repairs.push(Repair::DroppedInconsistentFace { face: index });
+                continue;
+            }
+            if constraints.contains(&true) {
+                if let Some(perimeter) = faces[index].as_mut() {
+                    perimeter.reverse();
+                }
+                repairs.push(Repair::ReversedFace { face: index });
+            }
+            is_oriented[index] = true;
+            for &(adjacent, _) in adjacency[index].iter() {
+                if faces[adjacent].is_some() && !is_visited[adjacent] {
+                    is_visited[adjacent] = true;
+                    queue.push_back(adjacent);
+                }
+            }
+        }
+    }
+}
+
+// Duplicates vertices that join disjoint fans of faces. Faces in fans beyond
+// the first are rewritten to index a copy of the vertex.
+//
+// Returns the indices of the source vertices of each copy. The copies follow
+// the `n` input vertices.
+fn separate(
+    n: usize,
+    faces: &mut [Option<SmallVec<[usize; 4]>>],
+    adjacency: &[Vec<(usize, (usize, usize))>],
+    repairs: &mut Vec<Repair>,
+) -> Vec<usize> {
+    let mut incident = vec![vec![]; n];
+    for (index, perimeter) in faces.iter().enumerate() {
+        if let Some(perimeter) = perimeter {
+            for &vertex in perimeter.iter() {
+                incident[vertex].push(index);
+            }
+        }
+    }
+    let mut sources = vec![];
+    for (vertex, incident) in incident.into_iter().enumerate() {
+        let mut unvisited = incident.into_iter().collect::<HashSet<_>>();
+        let mut is_first = true;
+        while let Some(&seed) = unvisited.iter().min() {
+            unvisited.remove(&seed);
+            let mut fan = vec![seed];
+            let mut stack = vec![seed];
+            while let Some(index) = stack.pop() {
+                for &(adjacent, (a, b)) in adjacency[index].iter() {
+                    if (a == vertex || b == vertex) && unvisited.remove(&adjacent) {
+                        fan.push(adjacent);
+                        stack.push(adjacent);
+                    }
+                }
+            }
+            if is_first {
+                is_first = false;
+                continue;
+            }
+            let copy = n + sources.len();
+            sources.push(vertex);
+            repairs.push(Repair::DuplicatedVertex { vertex });
+            for index in fan {
+                if let Some(perimeter) = faces[index].as_mut() {
+                    for key in perimeter.iter_mut().filter(|key| **key == vertex) {
+                        *key = copy;
+                    }
+                }
+            }
+        }
+    }
+    sources
+}
+
+fn edge(a: usize, b: usize) -> (usize, usize) {
+    (cmp::min(a, b), cmp::max(a, b))
+}
+
+// Determines if a perimeter traverses the edge from `a` to `b`.
+fn is_forward(perimeter: &[usize], a: usize, b: usize) -> bool {
+    perimeter
+        .iter()
+        .cloned()
+        .perimeter()
+        .any(|edge| edge == (a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::graph::{MeshGraph, Repair};
+    use crate::prelude::*;
+    use crate::primitive::Trigon;
+
+    type E3 = Point3<f64>;
+
+    fn vertices() -> Vec<(f64, f64, f64)> {
+        vec![
+            (0.0, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+            (0.0, -1.0, 0.0),
+            (0.0, 0.0, 1.0),
+        ]
+    }
+
+    #[test]
+    fn repair_degenerate_face() {
+        let (graph, repairs) = MeshGraph::<E3>::from_raw_buffers_with_repair(
+            vec![Trigon::new(0u32, 1, 2), Trigon::new(0, 0, 3)],
+            vertices(),
+        )
+        .unwrap();
+
+        assert_eq!(1, graph.face_count());
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(
+            vec![
+                Repair::DroppedDegenerateFace { face: 1 },
+                Repair::DroppedUnusedVertex { vertex: 3 },
+                Repair::DroppedUnusedVertex { vertex: 4 },
+            ],
+            repairs
+        );
+    }
+
+    #[test]
+    fn repair_unused_vertex() {
+        let (graph, repairs) = MeshGraph::<E3>::from_raw_buffers_with_repair(
+            vec![Trigon::new(0u32, 1, 2), Trigon::new(2, 1, 3)],
+            vertices(),
+        )
+        .unwrap();
+
+        assert_eq!(2, graph.face_count());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(vec![Repair::DroppedUnusedVertex { vertex: 4 }], repairs);
+    }
+
+    #[test]
+    fn repair_inconsistent_winding() {
+        // The faces reference every vertex, so only their winding prevents
+        // constructing a graph without repair.
+        let vertices = vertices().into_iter().take(4).collect::<Vec<_>>();
+        let indices = vec![Trigon::new(0u32, 1, 2), Trigon::new(0, 1, 3)];
+        assert!(MeshGraph::<E3>::from_raw_buffers(indices.clone(), vertices.clone()).is_err());
+
+        let (graph, repairs) =
+            MeshGraph::<E3>::from_raw_buffers_with_repair(indices, vertices).unwrap();
+
+        assert_eq!(2, graph.face_count());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(vec![Repair::ReversedFace { face: 1 }], repairs);
+    }
+
+    #[test]
+    fn repair_non_manifold_edge() {
+        // Three triangles share the edge between the first two vertices.
+        let (graph, repairs) = MeshGraph::<E3>::from_raw_buffers_with_repair(
+            vec![
+                Trigon::new(0u32, 1, 2),
+                Trigon::new(1, 0, 3),
+                Trigon::new(1, 0, 4),
+            ],
+            vertices(),
+        )
+        .unwrap();
+
+        assert_eq!(3, graph.face_count());
+        assert_eq!(7, graph.vertex_count());
+        assert_eq!(
+            vec![
+                Repair::DetachedFace {
+                    face: 2,
+                    edge: (0, 1)
+                },
+                Repair::DuplicatedVertex { vertex: 0 },
+                Repair::DuplicatedVertex { vertex: 1 },
+            ],
+            repairs
+        );
+    }
+
+    #[test]
+    fn repair_bowtie() {
+        let (graph, repairs) = MeshGraph::<E3>::from_raw_buffers_with_repair(
+            vec![Trigon::new(0u32, 1, 2), Trigon::new(0, 3, 4)],
+            vertices(),
+        )
+        .unwrap();
+
+        assert_eq!(2, graph.face_count());
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(2, graph.disjoint_subgraph_vertices().count());
+        assert_eq!(vec![Repair::DuplicatedVertex { vertex: 0 }], repairs);
+    }
+
+    #[test]
+    fn repair_out_of_bounds() {
+        assert!(MeshGraph::<E3>::from_raw_buffers_with_repair(
+            vec![Trigon::new(0u32, 1, 9)],
+            vertices(),
+        )
+        .is_err());
+    }
+}
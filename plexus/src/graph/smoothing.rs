@@ -0,0 +1,281 @@
+//! Smoothing of vertex positions.
+
+use num::{One, Zero};
+use std::collections::HashSet;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::AsPositionMut;
+
+use crate::graph::data::GraphData;
+use crate::graph::geometry::{self, VertexPosition};
+use crate::graph::vertex::{VertexKey, VertexView};
+use crate::graph::{
+    MeshGraph, OptionExt as _, SmoothingBoundary, SmoothingFilter, SmoothingWeight,
+};
+use crate::DynamicArity;
+
+/// Smooths the positions of vertices in a graph.
+pub fn smooth_with<G, I>(
+    graph: &mut MeshGraph<G>,
+    filter: SmoothingFilter<Scalar<VertexPosition<G>>>,
+    weight: SmoothingWeight,
+    boundary: SmoothingBoundary,
+    pinned: I,
+) where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: InnerSpace,
+    I: IntoIterator<Item = VertexKey>,
+{
+    let mut pinned = pinned.into_iter().collect::<HashSet<_>>();
+    if let SmoothingBoundary::Pin = boundary {
+        pinned.extend(
+            graph
+                .vertices()
+                .filter(|vertex| vertex.is_boundary_vertex())
+                .map(|vertex| vertex.key()),
+        );
+    }
+    match filter {
+        SmoothingFilter::Laplacian(factor) => {
+            relax(graph, factor, weight, &pinned);
+        }
+        SmoothingFilter::Taubin { lambda, mu } => {
+            relax(graph, lambda, weight, &pinned);
+            relax(graph, mu, weight, &pinned);
+        }
+    }
+}
+
+// Translates the position of each vertex that is not pinned by its offset from
+// the weighted mean of its adjacent vertices scaled by the given factor. All
+// offsets are computed before any positions are written.
+fn relax<G>(
+    graph: &mut MeshGraph<G>,
+    factor: Scalar<VertexPosition<G>>,
+    weight: SmoothingWeight,
+    pinned: &HashSet<VertexKey>,
+) where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: InnerSpace,
+{
+    let positions = graph
+        .vertices()
+        .filter(|vertex| !pinned.contains(&vertex.key()))
+        .map(|vertex| {
+            let position = *vertex.position();
+            let offset = match weight {
+                SmoothingWeight::Uniform => None,
+                SmoothingWeight::Cotangent => cotangent_offset(vertex),
+            }
+            .unwrap_or_else(|| vertex.centroid() - position);
+            (vertex.key(), position + (offset * factor))
+        })
+        .collect::<Vec<_>>();
+    for (key, position) in positions {
+        *graph
+            .vertex_mut(key)
+            .expect_consistent()
+            .get_mut()
+            .as_position_mut() = position;
+    }
+}
+
+// Gets the offset of a vertex from the mean of its adjacent vertices weighted
+// by the cotangents of the angles opposite the edges that connect them. For
+// each outgoing arc with a face, the opposite angle is measured at the vertex
+// that follows the arc in its ring, which is the opposite corner of a
+// triangle. Negative weights from obtuse angles are clamped to zero, which
+// keeps smoothing stable on irregular meshes. Returns `None` if any adjacent
+// face is not a triangle, as the angles are not defined, or if all weights are
+// zero, such as when the vertex is isolated or only adjacent to degenerate
+// faces.
+fn cotangent_offset<G>(vertex: VertexView<&MeshGraph<G>>) -> Option<Vector<VertexPosition<G>>>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: InnerSpace,
+{
+    if vertex.adjacent_faces().any(|face| face.arity() != 3) {
+        return None;
+    }
+    let position = *vertex.position();
+    let (sum, total) = vertex.outgoing_arcs().fold(
+        (Zero::zero(), Zero::zero()),
+        |(sum, total): (Vector<VertexPosition<G>>, Scalar<VertexPosition<G>>), arc| {
+            let weight = [arc, arc.opposite_arc()]
+                .iter()
+                .filter(|arc| !arc.is_boundary_arc())
+                .map(|arc| {
+                    geometry::cotangent(
+                        *arc.source_vertex().position(),
+                        *arc.destination_vertex().position(),
+                        *arc.next_arc().destination_vertex().position(),
+                    )
+                })
+                .filter(|cotangent| *cotangent > Zero::zero())
+                .fold(
+                    Zero::zero(),
+                    |total: Scalar<VertexPosition<G>>, cotangent| total + cotangent,
+                );
+            let offset = *arc.destination_vertex().position() - position;
+            (sum + (offset * weight), total + weight)
+        },
+    );
+    if total > Zero::zero() {
+        Some(sum * (<Scalar<VertexPosition<G>> as One>::one() / total))
+    }
+    else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::tests::grid;
+    use crate::graph::{MeshGraph, SmoothingBoundary, SmoothingFilter, SmoothingWeight};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+
+    type E3 = Point3<R64>;
+
+    fn positions(graph: &MeshGraph<Point3<f64>>) -> Vec<Point3<f64>> {
+        graph.vertices().map(|vertex| *vertex.position()).collect()
+    }
+
+    #[test]
+    fn smooth_plane_with_cotangent_weights() {
+        let mut graph = grid(4);
+        let expected = positions(&graph);
+        // Cotangent weights reproduce linear functions, so the positions of
+        // vertices in a plane are unchanged. Uniform weights skew vertices
+        // toward the diagonals of the grid.
+        graph.smooth_with(
+            SmoothingFilter::Laplacian(0.5),
+            SmoothingWeight::Cotangent,
+            SmoothingBoundary::Pin,
+            None,
+        );
+        for (expected, position) in expected.iter().zip(positions(&graph)) {
+            assert!((expected - position).magnitude() < 1e-9);
+        }
+        graph.smooth_with(
+            SmoothingFilter::Laplacian(0.5),
+            SmoothingWeight::Uniform,
+            SmoothingBoundary::Pin,
+            None,
+        );
+        assert_ne!(expected, positions(&graph));
+    }
+
+    #[test]
+    fn smooth_cube_with_cotangent_weights() {
+        let cube =
+            || -> MeshGraph<Point3<f64>> { Cube::new().polygons::<Position<E3>>().collect() };
+        let mut cotangent = cube();
+        let mut uniform = cube();
+        // Cotangent weights are not defined for quadrilaterals, so vertices of
+        // the cube are weighted uniformly.
+        cotangent.smooth_with(
+            SmoothingFilter::Laplacian(0.5),
+            SmoothingWeight::Cotangent,
+            SmoothingBoundary::Move,
+            None,
+        );
+        uniform.smooth_with(
+            SmoothingFilter::Laplacian(0.5),
+            SmoothingWeight::Uniform,
+            SmoothingBoundary::Move,
+            None,
+        );
+
+        assert_eq!(positions(&uniform), positions(&cotangent));
+    }
+
+    #[test]
+    fn smooth_plane_with_pinned_vertices() {
+        let mut graph = grid(4);
+        let boundary = graph
+            .vertices()
+            .filter(|vertex| vertex.outgoing_arcs().any(|arc| arc.is_boundary_arc()))
+            .map(|vertex| (vertex.key(), *vertex.position()))
+            .collect::<Vec<_>>();
+        let (key, position) = {
+            let vertex = graph
+                .vertices()
+                .find(|vertex| vertex.position() == &Point3::new(1.0, 1.0, 0.0))
+                .unwrap();
+            (vertex.key(), *vertex.position())
+        };
+        for _ in 0..4 {
+            graph.smooth_with(
+                SmoothingFilter::Laplacian(0.5),
+                SmoothingWeight::Uniform,
+                SmoothingBoundary::Pin,
+                Some(key),
+            );
+        }
+
+        assert_eq!(position, *graph.vertex(key).unwrap().position());
+        for (key, position) in boundary {
+            assert_eq!(position, *graph.vertex(key).unwrap().position());
+        }
+        graph.smooth_with(
+            SmoothingFilter::Laplacian(0.5),
+            SmoothingWeight::Uniform,
+            SmoothingBoundary::Move,
+            None,
+        );
+        // Corners are pulled inward when boundaries are not pinned.
+        assert!(graph
+            .vertices()
+            .all(|vertex| vertex.position() != &Point3::new(0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn smooth_sphere_with_taubin_filter() {
+        let radius = |graph: &MeshGraph<Point3<f64>>| {
+            graph
+                .vertices()
+                .map(|vertex| vertex.position().coords.magnitude())
+                .sum::<f64>()
+                / (graph.vertex_count() as f64)
+        };
+        let sphere = || -> MeshGraph<Point3<f64>> {
+            UvSphere::new(16, 16)
+                .polygons::<Position<E3>>()
+                .triangulate()
+                .collect()
+        };
+        let mut laplacian = sphere();
+        let mut taubin = sphere();
+        for _ in 0..8 {
+            laplacian.smooth_with(
+                SmoothingFilter::Laplacian(0.5),
+                SmoothingWeight::Uniform,
+                SmoothingBoundary::Move,
+                None,
+            );
+            taubin.smooth_with(
+                SmoothingFilter::Taubin {
+                    lambda: 0.5,
+                    mu: -0.53,
+                },
+                SmoothingWeight::Uniform,
+                SmoothingBoundary::Move,
+                None,
+            );
+        }
+
+        assert!(radius(&laplacian) < 0.95);
+        assert!(radius(&taubin) > 0.95);
+    }
+}
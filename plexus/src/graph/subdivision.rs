@@ -0,0 +1,376 @@
+//! Subdivision surfaces.
+
+use num::NumCast;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::iter;
+use theon::space::{EuclideanSpace, Scalar};
+use theon::AsPositionMut;
+
+use crate::entity::view::ClosedView;
+use crate::graph::conway;
+use crate::graph::data::GraphData;
+use crate::graph::edge::EdgeKey;
+use crate::graph::face::FaceKey;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::mutation::face::{self, FaceInsertCache};
+use crate::graph::mutation::{self, Immediate};
+use crate::graph::vertex::{VertexKey, VertexView};
+use crate::graph::{GraphError, MeshGraph, OptionExt as _, ResultExt as _};
+use crate::transact::Transact;
+use crate::{DynamicArity, IteratorExt as _};
+
+type Mutation<G> = mutation::Mutation<Immediate<MeshGraph<G>>>;
+
+/// Applies one level of Catmull–Clark subdivision to a graph.
+pub fn catmull_clark<G>(graph: &MeshGraph<G>) -> MeshGraph<G>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+{
+    let mut mutation = Mutation::from(MeshGraph::new());
+    let mut faces =
+        HashMap::<FaceKey, (VertexKey, VertexPosition<G>)>::with_capacity(graph.face_count());
+    for face in graph.faces() {
+        let position = centroid(face.adjacent_vertices().map(|vertex| *vertex.position()));
+        let key = insert_with_position(&mut mutation, face.arc().source_vertex(), position);
+        faces.insert(face.key(), (key, position));
+    }
+    let mut edges = HashMap::<EdgeKey, VertexKey>::with_capacity(graph.edge_count());
+    for edge in graph.edges() {
+        let arc = edge.arc();
+        // Edges that are not adjacent to any face are discarded.
+        if arc.adjacent_faces().len() == 0 {
+            continue;
+        }
+        let (a, b) = (
+            *arc.source_vertex().position(),
+            *arc.destination_vertex().position(),
+        );
+        // Boundary edge points are placed at the midpoint of the edge.
+        // Otherwise, edge points are placed at the centroid of the endpoints
+        // and the face points of the adjacent faces.
+        let position = if edge.is_boundary_edge() {
+            centroid([a, b])
+        }
+        else {
+            centroid(
+                [a, b].into_iter().chain(
+                    arc.adjacent_faces()
+                        .map(|face| faces.get(&face.key()).expect_consistent().1),
+                ),
+            )
+        };
+        let key = insert_with_position(&mut mutation, arc.source_vertex(), position);
+        edges.insert(edge.key(), key);
+    }
+    let mut vertices = HashMap::<VertexKey, VertexKey>::with_capacity(graph.vertex_count());
+    for vertex in graph.vertices() {
+        // Vertices that are not adjacent to any face are discarded.
+        if vertex.adjacent_faces().count() == 0 {
+            continue;
+        }
+        let p = *vertex.position();
+        // Edges that are not adjacent to any face are ignored.
+        let boundary = vertex
+            .outgoing_arcs()
+            .filter(|arc| arc.adjacent_faces().len() == 1)
+            .map(|arc| *arc.destination_vertex().position())
+            .collect::<Vec<_>>();
+        let valence = vertex.valence();
+        let position = if boundary.len() == 2 {
+            // Boundary vertices are placed at $\frac{3}{4}P + \frac{1}{8}(A +
+            // B)$, where $A$ and $B$ are adjacent along the boundary.
+            centroid(iter::repeat(p).take(6).chain(boundary))
+        }
+        else if boundary.is_empty() && valence >= 3 {
+            // Interior vertices are placed at $\frac{Q + 2R + (n - 3)P}{n}$,
+            // where $Q$ is the centroid of the adjacent face points and $R$
+            // is the centroid of the midpoints of the incident edges.
+            let q = centroid(
+                vertex
+                    .adjacent_faces()
+                    .map(|face| faces.get(&face.key()).expect_consistent().1),
+            );
+            let r = centroid(
+                vertex
+                    .adjacent_vertices()
+                    .map(|vertex| centroid([p, *vertex.position()])),
+            );
+            centroid(
+                iter::once(q)
+                    .chain(iter::repeat(r).take(2))
+                    .chain(iter::repeat(p).take(valence - 3)),
+            )
+        }
+        else {
+            // Corners, where more than two boundary edges meet, are fixed.
+            p
+        };
+        vertices.insert(
+            vertex.key(),
+            insert_with_position(&mut mutation, vertex, position),
+        );
+    }
+    for face in graph.faces() {
+        let c = faces.get(&face.key()).expect_consistent().0;
+        let arcs = face.adjacent_arcs().collect::<Vec<_>>();
+        for (previous, next) in arcs.into_iter().perimeter() {
+            let perimeter = [
+                *vertices
+                    .get(&next.source_vertex().key())
+                    .expect_consistent(),
+                *edges.get(&next.edge().key()).expect_consistent(),
+                c,
+                *edges.get(&previous.edge().key()).expect_consistent(),
+            ];
+            insert_face(&mut mutation, &perimeter, face.get().clone()).expect_consistent();
+        }
+    }
+    mutation
+        .commit()
+        .map_err(|(_, error)| error)
+        .expect_consistent()
+}
+
+/// Applies one level of Loop subdivision to a triangular graph.
+///
+/// # Errors
+///
+/// Returns an error if the graph contains a face that is not triangular.
+pub fn loop_scheme<G>(graph: &MeshGraph<G>) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+{
+    triangular(graph)?;
+    let mut mutation = Mutation::from(MeshGraph::new());
+    let mut edges = HashMap::<EdgeKey, VertexKey>::with_capacity(graph.edge_count());
+    for edge in graph.edges() {
+        let arc = edge.arc();
+        // Edges that are not adjacent to any face are discarded.
+        if arc.adjacent_faces().len() == 0 {
+            continue;
+        }
+        let (a, b) = (
+            *arc.source_vertex().position(),
+            *arc.destination_vertex().position(),
+        );
+        let position = if edge.is_boundary_edge() {
+            centroid([a, b])
+        }
+        else {
+            // Interior edge points are placed at $\frac{3}{8}(A + B) +
+            // \frac{1}{8}(C + D)$, where $C$ and $D$ are the opposing vertices
+            // of the adjacent triangles.
+            let (c, d) = (
+                *arc.next_arc().destination_vertex().position(),
+                *arc.opposite_arc()
+                    .next_arc()
+                    .destination_vertex()
+                    .position(),
+            );
+            centroid(
+                iter::repeat(a)
+                    .take(3)
+                    .chain(iter::repeat(b).take(3))
+                    .chain([c, d]),
+            )
+        };
+        let key = insert_with_position(&mut mutation, arc.source_vertex(), position);
+        edges.insert(edge.key(), key);
+    }
+    let mut vertices = HashMap::<VertexKey, VertexKey>::with_capacity(graph.vertex_count());
+    for vertex in graph.vertices() {
+        // Vertices that are not adjacent to any face are discarded.
+        if vertex.adjacent_faces().count() == 0 {
+            continue;
+        }
+        let p = *vertex.position();
+        // Edges that are not adjacent to any face are ignored.
+        let boundary = vertex
+            .outgoing_arcs()
+            .filter(|arc| arc.adjacent_faces().len() == 1)
+            .map(|arc| *arc.destination_vertex().position())
+            .collect::<Vec<_>>();
+        let valence = vertex.valence();
+        let position = if boundary.len() == 2 {
+            centroid(iter::repeat(p).take(6).chain(boundary))
+        }
+        else if boundary.is_empty() && valence >= 3 {
+            // Interior vertices are placed at $(1 - n\beta)P + \beta\sum{Q}$
+            // using Warren's weights, where $\beta = \frac{3}{16}$ if $n = 3$
+            // and $\beta = \frac{3}{8n}$ otherwise. $Q$ is the centroid of
+            // adjacent vertices.
+            let q = centroid(vertex.adjacent_vertices().map(|vertex| *vertex.position()));
+            if valence == 3 {
+                centroid(iter::repeat(p).take(7).chain(iter::repeat(q).take(9)))
+            }
+            else {
+                centroid(iter::repeat(p).take(5).chain(iter::repeat(q).take(3)))
+            }
+        }
+        else {
+            p
+        };
+        vertices.insert(
+            vertex.key(),
+            insert_with_position(&mut mutation, vertex, position),
+        );
+    }
+    for face in graph.faces() {
+        let arcs = face.adjacent_arcs().collect::<Vec<_>>();
+        let midpoints = arcs
+            .iter()
+            .map(|arc| *edges.get(&arc.edge().key()).expect_consistent())
+            .collect::<Vec<_>>();
+        for (index, arc) in arcs.iter().enumerate() {
+            let perimeter = [
+                *vertices.get(&arc.source_vertex().key()).expect_consistent(),
+                midpoints[index],
+                midpoints[(index + 2) % 3],
+            ];
+            insert_face(&mut mutation, &perimeter, face.get().clone())?;
+        }
+        insert_face(&mut mutation, &midpoints, face.get().clone())?;
+    }
+    mutation.commit().map_err(|(_, error)| error)
+}
+
+/// Applies one level of Doo–Sabin subdivision to a graph.
+pub fn doo_sabin<G>(graph: &MeshGraph<G>) -> MeshGraph<G>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+{
+    // Doo–Sabin subdivision shares its topology with the expand operator.
+    // Corner points are placed at the centroid of the vertex, the face
+    // centroid, and the midpoints of the incident edges of the face.
+    conway::expand_with(graph, |arc| {
+        let p = *arc.source_vertex().position();
+        let face = arc.face().expect_consistent();
+        let c = centroid(face.adjacent_vertices().map(|vertex| *vertex.position()));
+        centroid([
+            p,
+            c,
+            centroid([p, *arc.destination_vertex().position()]),
+            centroid([p, *arc.previous_arc().source_vertex().position()]),
+        ])
+    })
+    .expect_consistent()
+}
+
+/// Applies one level of Kobbelt's $\sqrt{3}$ subdivision to a triangular
+/// graph.
+///
+/// # Errors
+///
+/// Returns an error if the graph contains a face that is not triangular or if
+/// an edge cannot be flipped.
+pub fn sqrt3<G>(graph: &mut MeshGraph<G>) -> Result<(), GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    Scalar<VertexPosition<G>>: NumCast,
+{
+    triangular(graph)?;
+    let mut positions = HashMap::with_capacity(graph.vertex_count());
+    for vertex in graph.vertices() {
+        // Vertices along boundaries are fixed.
+        if vertex.adjacent_faces().count() == 0
+            || vertex
+                .outgoing_arcs()
+                .any(|arc| arc.edge().is_boundary_edge())
+        {
+            continue;
+        }
+        // Interior vertices are placed at $(1 - \alpha)P + \alpha{Q}$, where
+        // $\alpha = \frac{4 - 2\cos\frac{2\pi}{n}}{9}$ and $Q$ is the
+        // centroid of adjacent vertices.
+        let p = *vertex.position();
+        let q = centroid(vertex.adjacent_vertices().map(|vertex| *vertex.position()));
+        let n = vertex.valence() as f64;
+        let alpha = <Scalar<VertexPosition<G>> as NumCast>::from(
+            (4.0 - (2.0 * (2.0 * PI / n).cos())) / 9.0,
+        )
+        .ok_or(GraphError::Geometry)?;
+        positions.insert(vertex.key(), p + ((q - p) * alpha));
+    }
+    let arcs = graph
+        .edges()
+        .filter(|edge| !edge.is_boundary_edge())
+        .map(|edge| edge.arc().key())
+        .collect::<Vec<_>>();
+    let faces = graph.faces().map(|face| face.key()).collect::<Vec<_>>();
+    for key in faces {
+        graph.face_mut(key).expect_consistent().poke_at_centroid();
+    }
+    // Poking faces does not affect the arcs of their perimeters, so the
+    // original interior edges can be found and flipped by their arcs.
+    for key in arcs {
+        graph.arc_mut(key).expect_consistent().flip()?;
+    }
+    for mut vertex in graph.vertex_orphans() {
+        if let Some(position) = positions.remove(&vertex.key()) {
+            *vertex.get_mut().as_position_mut() = position;
+        }
+    }
+    Ok(())
+}
+
+fn triangular<G>(graph: &MeshGraph<G>) -> Result<(), GraphError>
+where
+    G: GraphData,
+{
+    if let Some(arity) = graph
+        .faces()
+        .map(|face| face.arity())
+        .find(|arity| *arity != 3)
+    {
+        Err(GraphError::ArityConflict {
+            expected: 3,
+            actual: arity,
+        })
+    }
+    else {
+        Ok(())
+    }
+}
+
+pub(in crate::graph) fn centroid<S, I>(points: I) -> S
+where
+    S: EuclideanSpace,
+    I: IntoIterator<Item = S>,
+{
+    S::centroid(points).expect_consistent()
+}
+
+pub(in crate::graph) fn insert_with_position<G>(
+    mutation: &mut Mutation<G>,
+    vertex: VertexView<&MeshGraph<G>>,
+    position: VertexPosition<G>,
+) -> VertexKey
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+{
+    let mut data = vertex.get().clone();
+    *data.as_position_mut() = position;
+    mutation::vertex::insert(mutation, data)
+}
+
+pub(in crate::graph) fn insert_face<G>(
+    mutation: &mut Mutation<G>,
+    perimeter: &[VertexKey],
+    data: G::Face,
+) -> Result<FaceKey, GraphError>
+where
+    G: GraphData,
+{
+    let cache = FaceInsertCache::from_storage(&*mutation, perimeter)?;
+    face::insert_with(mutation, cache, || (Default::default(), data))
+}
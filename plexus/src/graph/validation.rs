@@ -0,0 +1,256 @@
+//! Validation of graph topology and geometry.
+
+use std::collections::HashSet;
+
+use crate::entity::storage::prelude::*;
+use crate::graph::data::GraphData;
+use crate::graph::edge::{ArcKey, EdgeKey};
+use crate::graph::face::FaceKey;
+use crate::graph::vertex::VertexKey;
+use crate::graph::MeshGraph;
+
+/// Defect found by validating a [`MeshGraph`].
+///
+/// Each defect includes the keys of the offending entities. A graph that has
+/// only been manipulated via the mutation API has no defects. See
+/// [`MeshGraph::validate`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::validate`]: crate::graph::MeshGraph::validate
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Defect {
+    /// A vertex is not connected to any arcs.
+    IsolatedVertex { vertex: VertexKey },
+    /// A vertex is connected to arcs, but its leading arc is missing or is
+    /// not outgoing from the vertex.
+    VertexWithoutArc { vertex: VertexKey },
+    /// An arc is missing a required key or references a vertex, arc, edge, or
+    /// face that does not exist.
+    DanglingArc { arc: ArcKey },
+    /// An arc has no opposite arc.
+    ArcWithoutOpposite { arc: ArcKey },
+    /// An arc is not connected to its next and previous arcs, such as when
+    /// its next arc does not begin where it ends or does not lead back to it.
+    DisconnectedArc { arc: ArcKey },
+    /// An edge is not paired with exactly an arc and its opposite arc.
+    InconsistentEdge { edge: EdgeKey },
+    /// The ring of a face does not close or includes arcs that are not
+    /// associated with the face.
+    OpenFace { face: FaceKey },
+    /// A face has fewer than three arcs or visits a vertex more than once.
+    DegenerateFace { face: FaceKey },
+    /// A face has zero area, such as when its vertices are collinear or
+    /// coincident.
+    ZeroAreaFace { face: FaceKey },
+}
+
+/// Validates the topology of a graph.
+///
+/// The given function is called with the vertices of each face that is
+/// topologically sound and determines if that face has zero area.
+pub fn validate_with<G, F>(graph: &MeshGraph<G>, mut f: F) -> Vec<Defect>
+where
+    G: GraphData,
+    F: FnMut(&[VertexKey]) -> bool,
+{
+    let vertices = &graph.core.vertices;
+    let arcs = &graph.core.arcs;
+    let edges = &graph.core.edges;
+    let faces = &graph.core.faces;
+    let mut defects = vec![];
+
+    let incident = arcs
+        .iter()
+        .flat_map(|(key, _)| {
+            let (a, b) = key.into();
+            [a, b]
+        })
+        .collect::<HashSet<_>>();
+    for (key, vertex) in vertices.iter() {
+        if !incident.contains(&key) {
+            defects.push(Defect::IsolatedVertex { vertex: key });
+        }
+        else if !vertex.arc.map_or(false, |arc| {
+            let (source, _) = arc.into();
+            source == key && arcs.contains_key(&arc)
+        }) {
+            defects.push(Defect::VertexWithoutArc { vertex: key });
+        }
+    }
+
+    let mut inconsistent = HashSet::new();
+    for (key, arc) in arcs.iter() {
+        let (a, b) = key.into();
+        let opposite = arcs.get(&key.into_opposite());
+        if opposite.is_none() {
+            defects.push(Defect::ArcWithoutOpposite { arc: key });
+        }
+        let (next, previous, edge) = match (arc.next, arc.previous, arc.edge) {
+            (Some(next), Some(previous), Some(edge))
+                if arcs.contains_key(&next)
+                    && arcs.contains_key(&previous)
+                    && edges.contains_key(&edge)
+                    && vertices.contains_key(&a)
+                    && vertices.contains_key(&b)
+                    && arc.face.map_or(true, |face| faces.contains_key(&face)) =>
+            {
+                (next, previous, edge)
+            }
+            _ => {
+                defects.push(Defect::DanglingArc { arc: key });
+                continue;
+            }
+        };
+        let (c, _) = next.into();
+        let (_, d) = previous.into();
+        if c != b
+            || d != a
+            || arcs
+                .get(&next)
+                .map_or(true, |next| next.previous != Some(key))
+            || arcs
+                .get(&previous)
+                .map_or(true, |previous| previous.next != Some(key))
+        {
+            defects.push(Defect::DisconnectedArc { arc: key });
+        }
+        // The edge must lead with either the arc or its opposite arc and both
+        // arcs must be associated with the edge.
+        if edges.get(&edge).map_or(true, |data| {
+            data.arc != key && data.arc != key.into_opposite()
+        }) || opposite.map_or(false, |opposite| opposite.edge != Some(edge))
+        {
+            inconsistent.insert(edge);
+        }
+    }
+    for (key, edge) in edges.iter() {
+        if [edge.arc, edge.arc.into_opposite()]
+            .iter()
+            .any(|arc| arcs.get(arc).map_or(true, |arc| arc.edge != Some(key)))
+        {
+            inconsistent.insert(key);
+        }
+    }
+    defects.extend(
+        inconsistent
+            .into_iter()
+            .map(|edge| Defect::InconsistentEdge { edge }),
+    );
+
+    for (key, face) in faces.iter() {
+        match perimeter(graph, key, face.arc) {
+            Some(perimeter) => {
+                let set = perimeter.iter().collect::<HashSet<_>>();
+                if perimeter.len() < 3 || set.len() != perimeter.len() {
+                    defects.push(Defect::DegenerateFace { face: key });
+                }
+                else if f(&perimeter) {
+                    defects.push(Defect::ZeroAreaFace { face: key });
+                }
+            }
+            None => {
+                defects.push(Defect::OpenFace { face: key });
+            }
+        }
+    }
+    defects
+}
+
+// Gets the source vertices of the arcs in the ring of a face or `None` if the
+// ring does not close. A ring cannot include more arcs than are in the graph,
+// so the walk is bounded.
+fn perimeter<G>(graph: &MeshGraph<G>, face: FaceKey, arc: ArcKey) -> Option<Vec<VertexKey>>
+where
+    G: GraphData,
+{
+    let arcs = &graph.core.arcs;
+    let mut perimeter = vec![];
+    let mut key = arc;
+    for _ in 0..arcs.len() {
+        let data = arcs.get(&key).filter(|data| data.face == Some(face))?;
+        let (source, _) = key.into();
+        perimeter.push(source);
+        key = data.next?;
+        if key == arc {
+            return Some(perimeter);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point2, Point3};
+
+    use crate::entity::storage::prelude::*;
+    use crate::graph::vertex::Vertex;
+    use crate::graph::{Defect, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::Trigon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn validate_consistent_graph() {
+        let graph: MeshGraph<E3> = UvSphere::new(8, 8).polygons::<Position<E3>>().collect();
+
+        assert!(graph.validate().is_empty());
+        assert!(graph.validate_with_geometry().is_empty());
+    }
+
+    #[test]
+    fn validate_dangling_arc() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(3, 2).polygons::<Position<E3>>().collect();
+        let arc = graph.arcs().nth(0).unwrap();
+        let (key, face) = (arc.key(), arc.face().unwrap().key());
+        graph.core.arcs.get_mut(&key).unwrap().next = None;
+        let defects = graph.validate();
+
+        assert!(defects.contains(&Defect::DanglingArc { arc: key }));
+        assert!(defects.contains(&Defect::OpenFace { face }));
+    }
+
+    #[test]
+    fn validate_inconsistent_edge() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(3, 2).polygons::<Position<E3>>().collect();
+        let key = graph.arcs().nth(0).unwrap().key();
+        let (ab, cd) = {
+            let mut edges = graph.edges().map(|edge| edge.key());
+            (edges.next().unwrap(), edges.next().unwrap())
+        };
+        let edge = graph.arc(key).unwrap().edge().key();
+        let other = if edge == ab { cd } else { ab };
+        graph.core.arcs.get_mut(&key).unwrap().edge = Some(other);
+        let defects = graph.validate();
+
+        assert!(defects.contains(&Defect::InconsistentEdge { edge }));
+        assert!(defects.contains(&Defect::InconsistentEdge { edge: other }));
+    }
+
+    #[test]
+    fn validate_isolated_vertex() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(3, 2).polygons::<Position<E3>>().collect();
+        let vertex = graph.core.vertices.insert(Vertex::new(Point3::origin()));
+
+        assert_eq!(vec![Defect::IsolatedVertex { vertex }], graph.validate());
+    }
+
+    #[test]
+    fn validate_zero_area_face() {
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0u32, 1, 2)],
+            vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)],
+        )
+        .unwrap();
+        let face = graph.faces().nth(0).unwrap().key();
+
+        assert!(graph.validate().is_empty());
+        assert_eq!(
+            vec![Defect::ZeroAreaFace { face }],
+            graph.validate_with_geometry()
+        );
+    }
+}
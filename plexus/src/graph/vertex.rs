@@ -1,25 +1,29 @@
 use derivative::Derivative;
 use smallvec::SmallVec;
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
-use theon::space::Vector;
+use theon::space::{EuclideanSpace, Scalar, Vector};
 use theon::AsPosition;
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
-use crate::entity::dijkstra;
+use crate::entity::dijkstra::{self, MetricTree};
 use crate::entity::storage::prelude::*;
 use crate::entity::storage::{
     AsStorage, AsStorageMut, AsStorageOf, HashStorage, IncrementalKeyer, Key,
 };
 use crate::entity::traverse::{Adjacency, Breadth, Depth, Trace, TraceAny, TraceFirst, Traversal};
-use crate::entity::view::{ClosedView, Orphan, Rebind, Unbind, View};
+use crate::entity::view::{Bind, ClosedView, Orphan, Rebind, Unbind, View};
 use crate::entity::{Entity, Payload};
 use crate::geometry::Metric;
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
 use crate::graph::face::{Face, FaceOrphan, FaceView};
-use crate::graph::geometry::{VertexCentroid, VertexNormal, VertexPosition};
+use crate::graph::geometry::{
+    PrincipalCurvatures, VertexArea, VertexCentroid, VertexGaussianCurvature, VertexMeanCurvature,
+    VertexNormal, VertexPosition, VertexPrincipalCurvature,
+};
 use crate::graph::mutation::vertex::{self, VertexRemoveCache};
 use crate::graph::mutation::{self, Consistent, Immediate, Mutable};
 use crate::graph::path::Path;
@@ -254,7 +258,7 @@ where
 
     pub fn into_shortest_path_with<Q, F>(
         self,
-        mut key: VertexKey,
+        key: VertexKey,
         f: F,
     ) -> Result<Path<'static, B>, GraphError>
     where
@@ -262,6 +266,130 @@ where
         F: Fn(VertexView<&M>, VertexView<&M>) -> Q,
     {
         let metrics = dijkstra::metrics_with(self.to_ref(), Some(key), f)?;
+        self.into_path_with_metrics(key, &metrics)
+    }
+
+    /// Gets the shortest path to the vertex with the given key using A*
+    /// search.
+    ///
+    /// The function `f` computes the metric between adjacent vertices and the
+    /// function `h` estimates the metric from a vertex to the destination.
+    /// The estimate must never exceed the metric of any path to the
+    /// destination and must never decrease by more than the metric between
+    /// adjacent vertices. Given such a heuristic, this finds the same path as
+    /// [`VertexView::shortest_path_with`], but explores far fewer vertices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination is not reachable or if `f` returns
+    /// a negative metric.
+    ///
+    /// # Examples
+    ///
+    /// Finding the shortest path between vertices using the Euclidean distance
+    /// to the destination as a heuristic:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 16).polygons::<Position<E3>>().collect();
+    /// let distance = |a: &Point3<f64>, b: &Point3<f64>| R64::from((b - a).norm());
+    ///
+    /// let from = graph.vertices().nth(0).unwrap();
+    /// let to = graph
+    ///     .vertices()
+    ///     .max_by_key(|vertex| distance(from.position(), vertex.position()))
+    ///     .unwrap();
+    /// let path = from
+    ///     .shortest_path_with_heuristic(
+    ///         to.key(),
+    ///         |from, to| distance(from.position(), to.position()),
+    ///         |vertex| distance(vertex.position(), to.position()),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(to.key(), path.back().key());
+    /// ```
+    ///
+    /// [`VertexView::shortest_path_with`]: crate::graph::VertexView::shortest_path_with
+    pub fn shortest_path_with_heuristic<Q, F, H>(
+        &self,
+        key: VertexKey,
+        f: F,
+        h: H,
+    ) -> Result<Path<'static, &M>, GraphError>
+    where
+        Q: Copy + Metric,
+        F: Fn(VertexView<&M>, VertexView<&M>) -> Q,
+        H: Fn(VertexView<&M>) -> Q,
+    {
+        self.to_ref().into_shortest_path_with_heuristic(key, f, h)
+    }
+
+    pub fn into_shortest_path_with_heuristic<Q, F, H>(
+        self,
+        key: VertexKey,
+        f: F,
+        h: H,
+    ) -> Result<Path<'static, B>, GraphError>
+    where
+        Q: Copy + Metric,
+        F: Fn(VertexView<&M>, VertexView<&M>) -> Q,
+        H: Fn(VertexView<&M>) -> Q,
+    {
+        let metrics = dijkstra::metrics_with_heuristic(self.to_ref(), key, f, h)?;
+        self.into_path_with_metrics(key, &metrics)
+    }
+
+    /// Partitions vertices by their nearest seed.
+    ///
+    /// Seeds are this vertex and the vertices with the given keys. Returns a
+    /// map from each reachable vertex to its nearest seed and the metric of
+    /// the shortest path from that seed, where the function `f` computes the
+    /// metric between adjacent vertices. This forms a Voronoi partition of
+    /// the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a seed is not found or if `f` returns a negative
+    /// metric.
+    pub fn partition_with<Q, F, I>(
+        &self,
+        keys: I,
+        f: F,
+    ) -> Result<HashMap<VertexKey, (VertexKey, Q)>, GraphError>
+    where
+        Q: Copy + Metric,
+        F: Fn(VertexView<&M>, VertexView<&M>) -> Q,
+        I: IntoIterator<Item = VertexKey>,
+    {
+        let (storage, _) = self.to_ref().unbind();
+        let seeds = Some(Ok(self.to_ref()))
+            .into_iter()
+            .chain(
+                keys.into_iter()
+                    .map(|key| VertexView::bind(storage, key).ok_or(GraphError::TopologyNotFound)),
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(dijkstra::partition_with(seeds, f)?)
+    }
+
+    fn into_path_with_metrics<Q>(
+        self,
+        mut key: VertexKey,
+        metrics: &MetricTree<VertexKey, Q>,
+    ) -> Result<Path<'static, B>, GraphError> {
         let mut keys = vec![key];
         while let Some((Some(previous), _)) = metrics.get(&key) {
             key = *previous;
@@ -290,6 +418,45 @@ where
     {
         <G as VertexCentroid>::centroid(self.to_ref()).expect_consistent()
     }
+
+    /// Gets the mixed Voronoi area of the vertex.
+    ///
+    /// See [`VertexArea`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any adjacent face is not a triangle or if the
+    /// adjacent triangles have no area.
+    ///
+    /// [`VertexArea`]: crate::graph::VertexArea
+    pub fn area(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: VertexArea,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        <G as VertexArea>::area(self.to_ref())
+    }
+
+    /// Gets the Gaussian curvature of the vertex.
+    ///
+    /// See [`VertexGaussianCurvature`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area of the vertex cannot be computed. See
+    /// [`VertexView::area`].
+    ///
+    /// [`VertexGaussianCurvature`]: crate::graph::VertexGaussianCurvature
+    /// [`VertexView::area`]: crate::graph::VertexView::area
+    pub fn gaussian_curvature(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: VertexGaussianCurvature,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        <G as VertexGaussianCurvature>::gaussian_curvature(self.to_ref())
+    }
 }
 
 impl<B, M, G> VertexView<B>
@@ -309,6 +476,46 @@ where
     {
         <G as VertexNormal>::normal(self.to_ref())
     }
+
+    /// Gets the mean curvature of the vertex.
+    ///
+    /// See [`VertexMeanCurvature`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area or normal of the vertex cannot be
+    /// computed. See [`VertexView::area`].
+    ///
+    /// [`VertexMeanCurvature`]: crate::graph::VertexMeanCurvature
+    /// [`VertexView::area`]: crate::graph::VertexView::area
+    pub fn mean_curvature(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: VertexMeanCurvature,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        <G as VertexMeanCurvature>::mean_curvature(self.to_ref())
+    }
+
+    /// Gets the principal curvatures and directions of the vertex.
+    ///
+    /// See [`VertexPrincipalCurvature`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Gaussian or mean curvature of the vertex cannot
+    /// be computed or if the vertex has too few adjacent vertices to determine
+    /// its principal directions.
+    ///
+    /// [`VertexPrincipalCurvature`]: crate::graph::VertexPrincipalCurvature
+    pub fn principal_curvatures(&self) -> Result<PrincipalCurvatures<VertexPosition<G>>, GraphError>
+    where
+        G: VertexPrincipalCurvature,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        <G as VertexPrincipalCurvature>::principal_curvatures(self.to_ref())
+    }
 }
 
 /// Reachable API.
@@ -399,6 +606,13 @@ where
         self.to_ref().into_outgoing_arcs()
     }
 
+    // Determines if the vertex is connected to a boundary arc, which is either
+    // one of its outgoing arcs or their opposite arcs.
+    pub(in crate::graph) fn is_boundary_vertex(&self) -> bool {
+        self.outgoing_arcs()
+            .any(|arc| arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc())
+    }
+
     /// Gets an iterator that traverses adjacent vertices by breadth.
     ///
     /// The traversal moves from the vertex to its adjacent vertices and so on.
@@ -539,8 +753,6 @@ where
         + Mutable<Data = G>,
     G: GraphData,
 {
-    // TODO: This is not yet implemented, so examples use `no_run`. Run these
-    //       examples in doc tests once this no longer intentionally panics.
     /// Removes the vertex.
     ///
     /// Any and all dependent entities are also removed, such as arcs and edges
@@ -554,7 +766,7 @@ where
     ///
     /// Removing a corner from a cube by removing its vertex:
     ///
-    /// ```rust,no_run
+    /// ```rust
     /// # extern crate decorum;
     /// # extern crate nalgebra;
     /// # extern crate plexus;
@@ -571,6 +783,9 @@ where
     /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
     /// let key = graph.vertices().nth(0).unwrap().key();
     /// graph.vertex_mut(key).unwrap().remove();
+    ///
+    /// assert_eq!(7, graph.vertex_count());
+    /// assert_eq!(3, graph.face_count());
     /// ```
     pub fn remove(self) {
         // This should never fail here.
@@ -1266,6 +1481,38 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn remove_vertex() {
+        let mut graph: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .collect();
+        let key = graph.vertices().nth(0).unwrap().key();
+        graph.vertex_mut(key).unwrap().remove();
+
+        assert_eq!(7, graph.vertex_count());
+        assert_eq!(9, graph.edge_count());
+        assert_eq!(3, graph.face_count());
+        // The removed corner leaves a single boundary with six arcs.
+        let arc = graph.arcs().find(|arc| arc.is_boundary_arc()).unwrap();
+        assert_eq!(6, arc.into_ring().arcs().count());
+    }
+
+    #[test]
+    fn remove_vertex_of_triangle() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(-1.0, 0.0), (0.0, 1.0), (1.0, 0.0)],
+        )
+        .unwrap();
+        let key = graph.vertices().nth(0).unwrap().key();
+        graph.vertex_mut(key).unwrap().remove();
+
+        // Only the edge opposite the removed vertex remains.
+        assert_eq!(2, graph.vertex_count());
+        assert_eq!(1, graph.edge_count());
+        assert_eq!(0, graph.face_count());
+    }
+
     #[test]
     fn traverse_by_breadth() {
         let graph: MeshGraph<E3> = Cube::new()
@@ -1285,4 +1532,93 @@ mod tests {
         let vertex = graph.vertices().nth(0).unwrap();
         assert_eq!(graph.vertex_count(), vertex.traverse_by_depth().count());
     }
+
+    #[test]
+    fn curvature_of_sphere() {
+        let graph: MeshGraph<Point3<f64>> = UvSphere::new(32, 32)
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .collect();
+        let area = graph
+            .faces()
+            .map(|face| {
+                let mut positions = face.vertices().map(|vertex| *vertex.position());
+                let (a, b, c) = (
+                    positions.next().unwrap(),
+                    positions.next().unwrap(),
+                    positions.next().unwrap(),
+                );
+                (b - a).cross(&(c - a)).magnitude() / 2.0
+            })
+            .sum::<f64>();
+        let areas = graph.vertex_areas().unwrap();
+        let gaussian = graph.gaussian_curvatures().unwrap();
+
+        // Mixed Voronoi areas partition the surface and angle defects satisfy
+        // the Gauss-Bonnet theorem for a surface with an Euler characteristic
+        // of two.
+        assert_eq!(graph.vertex_count(), areas.len());
+        assert!((area - areas.values().sum::<f64>()).abs() < 1e-9);
+        let total = gaussian
+            .iter()
+            .map(|(key, curvature)| curvature * areas[key])
+            .sum::<f64>();
+        assert!((total - (4.0 * std::f64::consts::PI)).abs() < 1e-9);
+        for curvature in graph.mean_curvatures().unwrap().values() {
+            assert!((curvature - 1.0).abs() < 0.25);
+        }
+    }
+
+    #[test]
+    fn curvature_of_plane() {
+        // Construct a regular hexagonal fan of triangles in the plane.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            (0..6usize)
+                .map(|k| Trigon::new(0, k + 1, ((k + 1) % 6) + 1))
+                .collect::<Vec<_>>(),
+            Some((0.0, 0.0, 0.0))
+                .into_iter()
+                .chain((0..6).map(|k| {
+                    let angle = f64::from(k) * std::f64::consts::FRAC_PI_3;
+                    (angle.cos(), angle.sin(), 0.0)
+                }))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let vertex = graph
+            .vertices()
+            .find(|vertex| vertex.position() == &Point3::origin())
+            .unwrap();
+        let curvatures = vertex.principal_curvatures().unwrap();
+        let (u, v) = (curvatures.maximum_direction, curvatures.minimum_direction);
+
+        assert!((vertex.area().unwrap() - (3.0f64.sqrt() / 2.0)).abs() < 1e-9);
+        assert!(vertex.gaussian_curvature().unwrap().abs() < 1e-9);
+        assert!(vertex.mean_curvature().unwrap().abs() < 1e-9);
+        assert!(curvatures.maximum.abs() < 1e-9);
+        assert!(curvatures.minimum.abs() < 1e-9);
+        assert!(u.dot(&v).abs() < 1e-9);
+    }
+
+    #[test]
+    fn area_of_quadrilaterals() {
+        let graph: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .collect();
+
+        assert!(matches!(
+            graph.vertices().nth(0).unwrap().area(),
+            Err(GraphError::ArityConflict {
+                expected: 3,
+                actual: 4
+            })
+        ));
+        assert!(matches!(
+            graph.vertex_areas(),
+            Err(GraphError::ArityConflict {
+                expected: 3,
+                actual: 4
+            })
+        ));
+    }
 }
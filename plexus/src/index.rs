@@ -36,6 +36,11 @@
 //! (using a [`HashIndexer`] by default). A specific [`Indexer`] can be
 //! configured using the [`CollectWithIndexer`] trait.
 //!
+//! Indexers disambiguate vertices by the exact equality of their data. Vertices
+//! with positions that are only nearly equal, such as those read from polygon
+//! soups, can be merged by welding an iterator of $n$-gons via the
+//! [`WeldVertices`] trait before indexing or collecting it.
+//!
 //! # Examples
 //!
 //! Indexing data for a cube to create raw buffers and a [`MeshBuffer`]:
@@ -75,18 +80,23 @@
 //! [`IndexVertices`]: crate::index::IndexVertices
 //! [`NGon`]: crate::primitive::NGon
 //! [`UnboundedPolygon`]: crate::primitive::UnboundedPolygon
+//! [`WeldVertices`]: crate::index::WeldVertices
 //! [`primitive`]: crate::primitive
 
-use num::{Integer, NumCast, Unsigned};
+use num::{Integer, NumCast, ToPrimitive, Unsigned};
 use std::cmp;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::vec;
 use theon::adjunct::Map;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::{AsPosition, Position};
 use typenum::NonZero;
 
 use crate::constant::{Constant, ToType, TypeOf};
+use crate::geometry::weld;
 use crate::primitive::decompose::IntoVertices;
 use crate::primitive::Topological;
 use crate::{Monomorphic, StaticArity};
@@ -657,6 +667,119 @@ where
 {
 }
 
+/// Functions for welding the vertices of an iterator of $n$-gons.
+///
+/// [`Indexer`]s disambiguate vertices by the exact equality of their key data,
+/// so vertices with positions that differ only by floating-point noise (such as
+/// those read from polygon soups) are never merged. Welding instead matches
+/// vertices spatially and replaces the vertices that are within a given
+/// distance of each other with combined vertex data. The resulting $n$-gons
+/// contain equal vertex data where vertices have been welded and can then be
+/// collected into a mesh data structure.
+///
+/// Each cluster of welded vertices is matched against the first vertex in the
+/// cluster, so welding does not chain across vertices that are each within the
+/// tolerance of one another. Negative tolerances are clamped to zero, so
+/// vertices with coincident positions are always welded.
+///
+/// See [`MeshBuffer::weld_with`].
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point2;
+/// use plexus::graph::MeshGraph;
+/// use plexus::prelude::*;
+/// use plexus::primitive::Trigon;
+///
+/// type E2 = Point2<R64>;
+///
+/// let graph: MeshGraph<E2> = vec![
+///     Trigon::new((0.0, 0.0), (1.0, 0.0), (0.0, 1.0)),
+///     Trigon::new((0.0, 1.0 + 1e-9), (1.0 - 1e-9, 0.0), (1.0, 1.0)),
+/// ]
+/// .into_iter()
+/// .map_vertices(|(x, y)| E2::new(x.into(), y.into()))
+/// .weld_vertices(1e-6.into())
+/// .collect();
+///
+/// assert_eq!(4, graph.vertex_count());
+/// ```
+///
+/// [`Indexer`]: crate::index::Indexer
+/// [`MeshBuffer::weld_with`]: crate::buffer::MeshBuffer::weld_with
+pub trait WeldVertices<P>: Sized
+where
+    P: Topological,
+{
+    /// Welds the vertices of an iterator of $n$-gons that are within the given
+    /// distance of each other and combines their data using the given
+    /// function.
+    ///
+    /// The function receives the vertices of each cluster in the order in
+    /// which they appear in the iterator.
+    fn weld_vertices_with<F>(
+        self,
+        tolerance: Scalar<Position<P::Vertex>>,
+        f: F,
+    ) -> vec::IntoIter<P>
+    where
+        P::Vertex: AsPosition + Clone,
+        Position<P::Vertex>: EuclideanSpace,
+        Vector<Position<P::Vertex>>: InnerSpace,
+        Scalar<Position<P::Vertex>>: ToPrimitive,
+        F: FnMut(&[P::Vertex]) -> P::Vertex;
+
+    /// Welds the vertices of an iterator of $n$-gons that are within the given
+    /// distance of each other.
+    ///
+    /// Welded vertices take on the data of the first vertex in their cluster.
+    fn weld_vertices(self, tolerance: Scalar<Position<P::Vertex>>) -> vec::IntoIter<P>
+    where
+        P::Vertex: AsPosition + Clone,
+        Position<P::Vertex>: EuclideanSpace,
+        Vector<Position<P::Vertex>>: InnerSpace,
+        Scalar<Position<P::Vertex>>: ToPrimitive,
+    {
+        self.weld_vertices_with(tolerance, |vertices| vertices[0].clone())
+    }
+}
+
+impl<P, I> WeldVertices<P> for I
+where
+    I: Iterator<Item = P>,
+    P: Topological,
+{
+    fn weld_vertices_with<F>(self, tolerance: Scalar<Position<P::Vertex>>, f: F) -> vec::IntoIter<P>
+    where
+        P::Vertex: AsPosition + Clone,
+        Position<P::Vertex>: EuclideanSpace,
+        Vector<Position<P::Vertex>>: InnerSpace,
+        Scalar<Position<P::Vertex>>: ToPrimitive,
+        F: FnMut(&[P::Vertex]) -> P::Vertex,
+    {
+        let mut topologies = self.collect::<Vec<_>>();
+        let vertices = topologies
+            .iter()
+            .flat_map(|topology| topology.as_ref().iter().cloned())
+            .collect::<Vec<_>>();
+        let (indices, vertices) = weld::weld_with(vertices, tolerance, f);
+        for (vertex, index) in topologies
+            .iter_mut()
+            .flat_map(|topology| topology.as_mut().iter_mut())
+            .zip(indices)
+        {
+            *vertex = vertices[index].clone();
+        }
+        topologies.into_iter()
+    }
+}
+
 pub trait FromIndexer<P, Q>: Sized
 where
     P: Topological,
@@ -74,10 +74,11 @@ pub mod prelude {
     pub use crate::builder::{FacetBuilder as _, MeshBuilder as _, SurfaceBuilder as _};
     pub use crate::geometry::{FromGeometry as _, IntoGeometry as _};
     pub use crate::graph::{ClosedView as _, Rebind as _, Selector};
-    pub use crate::index::{CollectWithIndexer as _, IndexVertices as _};
+    pub use crate::index::{CollectWithIndexer as _, IndexVertices as _, WeldVertices as _};
     pub use crate::primitive::decompose::{
-        Edges as _, IntoEdges as _, IntoSubdivisions as _, IntoTetrahedrons as _, IntoTrigons as _,
-        IntoVertices as _, Subdivide as _, Tetrahedrons as _, Triangulate as _, Vertices as _,
+        Edges as _, IntoDelaunayTrigons as _, IntoEdges as _, IntoSubdivisions as _,
+        IntoTetrahedrons as _, IntoTrigons as _, IntoVertices as _, Subdivide as _,
+        Tetrahedrons as _, Triangulate as _, TriangulateDelaunay as _, Vertices as _,
     };
     pub use crate::primitive::generate::Generator as _;
     pub use crate::primitive::{
@@ -71,9 +71,11 @@ use arrayvec::ArrayVec;
 use std::collections::VecDeque;
 use std::iter::IntoIterator;
 use theon::ops::Interpolate;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
 use typenum::{Cmp, Greater, U1};
 
 use crate::constant::{Constant, ToType, TypeOf};
+use crate::geometry::triangulation::{self, PlaneProjection, Triangulation};
 use crate::primitive::{
     BoundedPolygon, Edge, NGon, Polygonal, Tetragon, Topological, Trigon, UnboundedPolygon,
 };
@@ -194,6 +196,65 @@ pub trait IntoTrigons: Polygonal {
     fn into_trigons(self) -> Self::Output;
 }
 
+/// Conversion into [`Trigon`]s via a constrained Delaunay triangulation.
+///
+/// The vertices of the polygon are projected onto their best-fit plane and
+/// triangulated such that no vertex lies within the circumcircle of any
+/// triangle, except where prevented by the perimeter of the polygon. This
+/// avoids the sliver triangles that are typical of fan triangulations, such
+/// as those produced by [`IntoTrigons`].
+///
+/// Degenerate polygons, such as polygons with collinear vertices, are
+/// decomposed into a fan of triangles.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point2;
+/// use plexus::prelude::*;
+/// use plexus::primitive::{NGon, UnboundedPolygon};
+///
+/// type E2 = Point2<f64>;
+///
+/// // An L-shaped polygon.
+/// let polygon = UnboundedPolygon::from(NGon([
+///     E2::new(0.0, 0.0),
+///     E2::new(2.0, 0.0),
+///     E2::new(2.0, 1.0),
+///     E2::new(1.0, 1.0),
+///     E2::new(1.0, 2.0),
+///     E2::new(0.0, 2.0),
+/// ]));
+/// let trigons = polygon.into_delaunay_trigons();
+///
+/// assert_eq!(4, trigons.len());
+/// ```
+///
+/// [`IntoTrigons`]: crate::primitive::decompose::IntoTrigons
+/// [`Trigon`]: crate::primitive::Trigon
+pub trait IntoDelaunayTrigons: Polygonal
+where
+    Self::Vertex: EuclideanSpace,
+{
+    type Output: IntoIterator<Item = Trigon<Self::Vertex>>;
+
+    fn into_delaunay_trigons(self) -> Self::Output;
+
+    /// Converts the polygon into [`Trigon`]s and refines the triangulation
+    /// with Steiner points.
+    ///
+    /// Steiner points are inserted into the interior of the polygon at the
+    /// circumcenters of triangles with angles smaller than the given minimum
+    /// angle (in radians). The perimeter of the polygon is never split, so
+    /// the minimum angle is not guaranteed.
+    ///
+    /// [`Trigon`]: crate::primitive::Trigon
+    fn into_refined_delaunay_trigons(self, angle: Scalar<Self::Vertex>) -> Self::Output;
+}
+
 pub trait IntoSubdivisions: Polygonal {
     type Output: IntoIterator<Item = Self>;
 
@@ -295,6 +356,38 @@ where
     }
 }
 
+impl<T> IntoDelaunayTrigons for BoundedPolygon<T>
+where
+    T: EuclideanSpace,
+    Vector<T>: InnerSpace,
+{
+    type Output = Vec<Trigon<Self::Vertex>>;
+
+    fn into_delaunay_trigons(self) -> Self::Output {
+        delaunay(self.into_iter().collect(), None)
+    }
+
+    fn into_refined_delaunay_trigons(self, angle: Scalar<Self::Vertex>) -> Self::Output {
+        delaunay(self.into_iter().collect(), Some(angle))
+    }
+}
+
+impl<T> IntoDelaunayTrigons for UnboundedPolygon<T>
+where
+    T: EuclideanSpace,
+    Vector<T>: InnerSpace,
+{
+    type Output = Vec<Trigon<Self::Vertex>>;
+
+    fn into_delaunay_trigons(self) -> Self::Output {
+        delaunay(self.into_iter().collect(), None)
+    }
+
+    fn into_refined_delaunay_trigons(self, angle: Scalar<Self::Vertex>) -> Self::Output {
+        delaunay(self.into_iter().collect(), Some(angle))
+    }
+}
+
 impl<T> IntoSubdivisions for Trigon<T>
 where
     T: Clone + Interpolate<Output = T>,
@@ -420,6 +513,25 @@ where
     }
 }
 
+pub trait TriangulateDelaunay<P>: Sized
+where
+    P: IntoDelaunayTrigons,
+    P::Vertex: EuclideanSpace,
+{
+    fn triangulate_delaunay(self) -> Decompose<Self, P, Trigon<P::Vertex>, P::Output>;
+}
+
+impl<I, P> TriangulateDelaunay<P> for I
+where
+    I: Iterator<Item = P>,
+    P: IntoDelaunayTrigons,
+    P::Vertex: EuclideanSpace,
+{
+    fn triangulate_delaunay(self) -> Decompose<Self, P, Trigon<P::Vertex>, P::Output> {
+        Decompose::new(self, P::into_delaunay_trigons)
+    }
+}
+
 pub trait Subdivide<P>: Sized
 where
     P: IntoSubdivisions,
@@ -453,6 +565,39 @@ where
     }
 }
 
+fn delaunay<T>(vertices: Vec<T>, angle: Option<Scalar<T>>) -> Vec<Trigon<T>>
+where
+    T: EuclideanSpace,
+    Vector<T>: InnerSpace,
+{
+    let n = vertices.len();
+    PlaneProjection::from_points(&vertices)
+        .and_then(|projection| {
+            let points = vertices
+                .iter()
+                .map(|vertex| projection.project(*vertex))
+                .collect::<Vec<_>>();
+            triangulation::delaunay_with(&points, angle, |_, _| true)
+                .map(|triangulation| (projection, triangulation))
+        })
+        .map(|(projection, Triangulation { points, triangles })| {
+            let vertices = vertices
+                .iter()
+                .cloned()
+                .chain(points[n..].iter().map(|point| projection.embed(*point)))
+                .collect::<Vec<_>>();
+            triangles
+                .into_iter()
+                .map(|[a, b, c]| Trigon::new(vertices[a], vertices[b], vertices[c]))
+                .collect()
+        })
+        .unwrap_or_else(|| {
+            (1..(n - 1))
+                .map(|index| Trigon::new(vertices[0], vertices[index], vertices[index + 1]))
+                .collect()
+        })
+}
+
 fn remap<I, P, R, F>(n: usize, ngons: I, f: F) -> Vec<P>
 where
     I: IntoIterator<Item = P>,